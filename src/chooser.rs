@@ -0,0 +1,174 @@
+use crate::{
+    commands::search::{Pager, PagerPress},
+    content::{CharacterHit, FreeCompanyHit, FreeCompanyMember},
+    Context, Error,
+};
+use poise::serenity_prelude as serenity;
+
+/// one of the options a chooser lets the user pick from.
+pub struct Choice {
+    pub name: String,
+    /// a short line shown under the name, e.g. the world.
+    pub description: String,
+    pub thumbnail: Option<String>,
+    pub url: String,
+}
+
+impl From<&CharacterHit> for Choice {
+    fn from(hit: &CharacterHit) -> Self {
+        Self {
+            name: hit.name.clone(),
+            description: hit.server.clone(),
+            thumbnail: Some(hit.avatar.clone()),
+            url: format!(
+                "https://na.finalfantasyxiv.com/lodestone/character/{}",
+                hit.id
+            ),
+        }
+    }
+}
+
+impl From<&FreeCompanyHit> for Choice {
+    fn from(hit: &FreeCompanyHit) -> Self {
+        Self {
+            name: hit.name.clone(),
+            description: hit.server.clone(),
+            thumbnail: hit.crest.get(1).or(hit.crest.first()).cloned(),
+            url: format!(
+                "https://na.finalfantasyxiv.com/lodestone/freecompany/{}",
+                hit.id
+            ),
+        }
+    }
+}
+
+impl From<&FreeCompanyMember> for Choice {
+    fn from(member: &FreeCompanyMember) -> Self {
+        Self {
+            name: member.name.clone(),
+            description: member.rank.clone(),
+            thumbnail: Some(member.avatar.clone()),
+            url: format!(
+                "https://na.finalfantasyxiv.com/lodestone/character/{}",
+                member.id
+            ),
+        }
+    }
+}
+
+fn choice_embed<'a>(
+    e: &'a mut serenity::CreateEmbed,
+    choice: &Choice,
+) -> &'a mut serenity::CreateEmbed {
+    e.title(&choice.name)
+        .description(&choice.description)
+        .url(&choice.url);
+
+    if let Some(thumbnail) = &choice.thumbnail {
+        e.thumbnail(thumbnail);
+    }
+
+    e
+}
+
+/// adds the select menu for a page of choices, plus the navigation buttons if there's more
+/// than one page.
+fn chooser_components<'a>(
+    c: &'a mut serenity::CreateComponents,
+    pager: &Pager,
+    select: &str,
+    placeholder: &str,
+    page: &[Choice],
+) -> &'a mut serenity::CreateComponents {
+    c.create_action_row(|r| {
+        r.create_select_menu(|m| {
+            m.custom_id(select).placeholder(placeholder).options(|o| {
+                for (i, choice) in page.iter().enumerate() {
+                    o.create_option(|o| {
+                        o.label(&choice.name)
+                            .value(pager.offset() + i)
+                            .description(&choice.description)
+                    });
+                }
+                o
+            })
+        })
+    });
+
+    pager.buttons(c)
+}
+
+/// lets the user pick one of several choices, five at a time, returning its index.
+///
+/// a single choice is picked straight away. returns `None` if the user doesn't pick one in
+/// time.
+pub async fn choose(
+    ctx: Context<'_>,
+    choices: &[Choice],
+    placeholder: String,
+) -> Result<Option<usize>, Error> {
+    if choices.len() == 1 {
+        return Ok(Some(0));
+    }
+
+    let mut pager = Pager::new(ctx, choices.len(), 5).author_only(ctx);
+    let select = pager.id("select");
+
+    ctx.send(|b| {
+        let page = pager.items(choices);
+        for choice in page {
+            b.embed(|e| choice_embed(e, choice));
+        }
+
+        b.components(|c| chooser_components(c, &pager, &select, &placeholder, page))
+    })
+    .await?;
+
+    while let Some(press) = pager.next_press(ctx).await {
+        let press = match press {
+            PagerPress::Turned(press) => press,
+            PagerPress::Component(press) if press.data.custom_id == select => {
+                let index = match press
+                    .data
+                    .values
+                    .first()
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .filter(|i| *i < choices.len())
+                {
+                    Some(i) => i,
+                    None => continue,
+                };
+
+                press
+                    .create_interaction_response(ctx, |b| {
+                        b.kind(serenity::InteractionResponseType::UpdateMessage)
+                            .interaction_response_data(|b| {
+                                b.embed(|e| choice_embed(e, &choices[index]))
+                                    .components(|c| c)
+                            })
+                    })
+                    .await?;
+
+                return Ok(Some(index));
+            }
+            PagerPress::Component(_) => continue,
+        };
+
+        let page = pager.items(choices);
+
+        press
+            .create_interaction_response(ctx, |b| {
+                b.kind(serenity::InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|b| {
+                        for choice in page {
+                            b.embed(|e| choice_embed(e, choice));
+                        }
+
+                        b.components(|c| chooser_components(c, &pager, &select, &placeholder, page))
+                    })
+            })
+            .await?;
+    }
+
+    Ok(None)
+}
@@ -0,0 +1,66 @@
+use crate::{
+    history::{announce_key, ANNOUNCE_GUILDS_KEY},
+    i18n::tr,
+    Context, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::AsyncCommands;
+
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_GUILD",
+    subcommands("channel", "disable"),
+    subcommand_required
+)]
+pub async fn announcements(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// announce members' level-ups and milestones in a channel.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn channel(
+    ctx: Context<'_>,
+    #[description = "the channel to announce in"]
+    #[channel_types("Text")]
+    channel: serenity::GuildChannel,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con.set(announce_key(guild_id), channel.id.0).await?;
+    let _: () = con.sadd(ANNOUNCE_GUILDS_KEY, guild_id.0).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "announcements-updated")).description(tr!(
+                ctx,
+                "announcements-updated.channel",
+                channel: format!("<#{}>", channel.id)
+            ))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// stop announcing members' progress in this server.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn disable(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con.del(announce_key(guild_id)).await?;
+    let _: () = con.srem(ANNOUNCE_GUILDS_KEY, guild_id.0).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "announcements-updated"))
+                .description(tr!(ctx, "announcements-updated.disabled"))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -1,20 +1,268 @@
-use crate::{Context, Error};
-use redis::{AsyncCommands, RedisError};
-use std::str::FromStr;
-use xivapi::{
-    models::character::CharacterResult,
-    prelude::{Builder, World},
+use crate::{
+    chooser::{self, Choice},
+    commands::{free_company::free_company_embed, search},
+    content::field,
+    free_company, history,
+    i18n::tr,
+    job,
+    language::{self, Language},
+    link, world, Context, Error,
 };
+use poise::serenity_prelude as serenity;
+use rand::distributions::{Alphanumeric, DistString};
+use redis::AsyncCommands;
+use serde_json::Value;
+use tracing::warn;
+use xivapi::models::character::CharacterResult;
 
-async fn return_embed(
+/// the custom IDs of the character card's components.
+struct CardIds {
+    jobs: String,
+    gear: String,
+    /// only set when the character is in a free company.
+    free_company: Option<String>,
+    back: String,
+}
+
+/// renders one of a character's detail views from their full row.
+type View =
+    for<'a> fn(Context<'_>, &'a mut serenity::CreateEmbed, &Value) -> &'a mut serenity::CreateEmbed;
+
+/// the buttons under the character card, switching to its other views.
+fn card_components<'a>(
+    ctx: Context<'_>,
+    c: &'a mut serenity::CreateComponents,
+    ids: &CardIds,
+) -> &'a mut serenity::CreateComponents {
+    c.create_action_row(|r| {
+        r.create_button(|b| {
+            b.custom_id(&ids.jobs)
+                .style(serenity::ButtonStyle::Secondary)
+                .label(tr!(ctx, "character-job-levels.button"))
+        })
+        .create_button(|b| {
+            b.custom_id(&ids.gear)
+                .style(serenity::ButtonStyle::Secondary)
+                .label(tr!(ctx, "character-equipment.button"))
+        });
+
+        if let Some(free_company) = &ids.free_company {
+            r.create_button(|b| {
+                b.custom_id(free_company)
+                    .style(serenity::ButtonStyle::Secondary)
+                    .label(tr!(ctx, "character-free-company.button"))
+            });
+        }
+
+        r
+    })
+}
+
+/// the button under one of the character card's other views, switching back to the card.
+fn back_components<'a>(
+    ctx: Context<'_>,
+    c: &'a mut serenity::CreateComponents,
+    ids: &CardIds,
+) -> &'a mut serenity::CreateComponents {
+    c.create_action_row(|r| {
+        r.create_button(|b| b.custom_id(&ids.back).label(tr!(ctx, "character-back")))
+    })
+}
+
+/// a single class or job's level and progress towards the next, or `None` if it's locked.
+fn job_line(ctx: Context<'_>, job: &Value) -> Option<String> {
+    let level = job["Level"].as_u64().filter(|l| *l > 0)?;
+    let name = field(job, "UnlockedState.Name")?;
+    let current = job["ExpLevel"].as_u64().unwrap_or(0);
+    let max = job["ExpLevelMax"].as_u64().unwrap_or(0);
+
+    Some(if max == 0 {
+        tr!(ctx, "character-job.max", job: name, level: level)
+    } else {
+        tr!(
+            ctx,
+            "character-job",
+            job: name,
+            level: level,
+            progress: current * 100 / max
+        )
+    })
+}
+
+/// every unlocked class and job of a character, grouped by role.
+fn jobs_embed<'a>(
+    ctx: Context<'_>,
+    e: &'a mut serenity::CreateEmbed,
+    row: &Value,
+) -> &'a mut serenity::CreateEmbed {
+    let jobs = row
+        .pointer("/Character/ClassJobs")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    e.title(tr!(
+        ctx,
+        "character-job-levels",
+        name: field(row, "Character.Name").unwrap_or_default()
+    ));
+
+    if let Some(avatar) = field(row, "Character.Avatar") {
+        e.thumbnail(avatar);
+    }
+
+    for role in job::ROLES {
+        let lines: Vec<_> = role
+            .jobs
+            .iter()
+            .filter_map(|id| jobs.iter().find(|j| j["JobID"].as_u64() == Some(*id)))
+            .filter_map(|j| job_line(ctx, j))
+            .collect();
+
+        if !lines.is_empty() {
+            e.field(tr!(ctx, role.name), lines.join("\n"), true);
+        }
+    }
+
+    e
+}
+
+/// the gear slots shown in the gear view, with their message catalog names.
+const GEAR_SLOTS: &[(&str, &str)] = &[
+    ("MainHand", "gear-slot-main-hand"),
+    ("OffHand", "gear-slot-off-hand"),
+    ("Head", "gear-slot-head"),
+    ("Body", "gear-slot-body"),
+    ("Hands", "gear-slot-hands"),
+    ("Legs", "gear-slot-legs"),
+    ("Feet", "gear-slot-feet"),
+    ("Earrings", "gear-slot-earrings"),
+    ("Necklace", "gear-slot-necklace"),
+    ("Bracelets", "gear-slot-bracelets"),
+    ("Ring1", "gear-slot-ring"),
+    ("Ring2", "gear-slot-ring"),
+];
+
+/// a character's average item level, the way the game computes it.
+///
+/// the soul crystal doesn't count, and a two-handed main hand counts twice in place of the
+/// off hand.
+pub fn average_item_level(gear: &Value) -> u64 {
+    let level = |slot: &str| gear.pointer(&format!("/{}/Item/LevelItem", slot))?.as_u64();
+
+    let mut total: u64 = GEAR_SLOTS.iter().filter_map(|(slot, _)| level(slot)).sum();
+    // a one-handed weapon with nothing in the off hand just leaves that slot at 0.
+    if gear["OffHand"].is_null() && is_two_handed(&gear["MainHand"]) {
+        total += level("MainHand").unwrap_or(0);
+    }
+
+    total / 12
+}
+
+/// whether an equipped main hand weapon takes up the off hand too, which its equip slot
+/// category marks by blocking the off hand.
+fn is_two_handed(weapon: &Value) -> bool {
+    let item = &weapon["Item"];
+
+    item["EquipSlotCategory"]["OffHand"].as_i64() == Some(-1)
+        // category 13 is the two-handed main hand, for rows that only link to it by ID.
+        || item["EquipSlotCategoryTargetID"].as_u64() == Some(13)
+}
+
+/// an equipped piece's name, item level, materia and dyes.
+fn gear_line(ctx: Context<'_>, piece: &Value) -> Option<String> {
+    let mut lines = vec![tr!(
+        ctx,
+        "character-gear-item",
+        item: field(piece, "Item.Name")?,
+        level: field(piece, "Item.LevelItem").unwrap_or_default()
+    )];
+
+    let materia: Vec<_> = piece["Materia"]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|m| field(m, "Name"))
+        .collect();
+    if !materia.is_empty() {
+        lines.push(tr!(ctx, "character-gear-item.materia", materia: materia.join(", ")));
+    }
+
+    let dyes: Vec<_> = ["Dye", "Dye2"]
+        .iter()
+        .filter_map(|dye| field(piece, &format!("{}.Name", dye)))
+        .collect();
+    if !dyes.is_empty() {
+        lines.push(tr!(ctx, "character-gear-item.dye", dye: dyes.join(", ")));
+    }
+
+    Some(lines.join("\n"))
+}
+
+/// every equipped piece of a character's gear, their average item level and soul crystal.
+fn gear_embed<'a>(
+    ctx: Context<'_>,
+    e: &'a mut serenity::CreateEmbed,
+    row: &Value,
+) -> &'a mut serenity::CreateEmbed {
+    let gear = row
+        .pointer("/Character/GearSet/Gear")
+        .unwrap_or(&Value::Null);
+
+    e.title(tr!(
+        ctx,
+        "character-equipment",
+        name: field(row, "Character.Name").unwrap_or_default()
+    ))
+    .description(tr!(
+        ctx,
+        "character-equipment.average",
+        level: average_item_level(gear)
+    ));
+
+    if let Some(portrait) = field(row, "Character.Portrait") {
+        e.thumbnail(portrait);
+    }
+
+    for (slot, name) in GEAR_SLOTS {
+        if let Some(line) = gear.get(slot).and_then(|piece| gear_line(ctx, piece)) {
+            e.field(tr!(ctx, name), line, true);
+        }
+    }
+
+    if let Some(crystal) = field(gear, "SoulCrystal.Item.Name") {
+        e.field(tr!(ctx, "gear-slot-soul-crystal"), crystal, false);
+    }
+
+    e
+}
+
+/// show a character's card, with buttons for its other views.
+pub async fn return_embed(
     method: &str,
-    response: Result<CharacterResult, xivapi::error::Error>,
-    ctx: &Context<'_>,
+    response: Result<CharacterResult, reqwest::Error>,
+    unverified: bool,
+    language: Language,
+    ctx: Context<'_>,
 ) -> Result<(), Error> {
-    match response {
-        Ok(r) => {
-            let character = r.character.unwrap();
-            let title = match &character.free_company_name {
+    match response.map(|r| r.character) {
+        Ok(Some(character)) => {
+            let fc_id = character.free_company_id.as_ref().map(|f| f.0.to_string());
+            let tag = match &fc_id {
+                Some(id) => {
+                    let mut con = ctx.data().client.get_async_connection().await?;
+
+                    free_company::tag(&mut con, &ctx.data().content, id)
+                        .await
+                        .unwrap_or_else(|e| {
+                            warn!("failed to fetch the tag of free company {}: {}", id, e);
+                            None
+                        })
+                }
+                None => None,
+            };
+            let title = match &tag {
                 Some(t) => format!(
                     "[{}] {} «{}»",
                     character.active_class_job.unlocked_state.name, character.name, t
@@ -25,44 +273,145 @@ async fn return_embed(
                 ),
             };
 
-            // TODO: implement fc tag for character, currently displaying fc name as placeholder.
+            let mut description = format!(
+                "{}\n```{}```",
+                tr!(ctx, "character-lodestone-id", id: character.id.0.to_string()),
+                character.bio
+            );
+            if unverified {
+                description = format!("{}\n{}", tr!(ctx, "character-unverified"), description);
+            }
+
             // TODO: implement Display for tribe and race.
             // TODO: further cleanup the embed.
+            let card = |e: &mut serenity::CreateEmbed| {
+                e.title(&title)
+                    .description(&description)
+                    .url(format!(
+                        "https://na.finalfantasyxiv.com/lodestone/character/{:?}",
+                        character.id.0
+                    ))
+                    .thumbnail(&character.avatar)
+                    .field(
+                        tr!(ctx, "character-information"),
+                        format!(
+                            "{:?} {:?}\n{:?}",
+                            character.tribe, character.race, character.gender
+                        ),
+                        true,
+                    )
+                    .field(
+                        tr!(ctx, "character-city-state"),
+                        format!("{:?}", character.town),
+                        true,
+                    )
+                    .field(tr!(ctx, "character-nameday"), &character.nameday, false)
+                    .footer(|f| {
+                        f.text(tr!(ctx, "character-world", world: character.world.to_string()))
+                    });
+
+                if let (Some(name), Some(id)) = (&character.free_company_name, &fc_id) {
+                    e.field(
+                        tr!(ctx, "character-free-company"),
+                        format!(
+                            "[{}](https://na.finalfantasyxiv.com/lodestone/freecompany/{})",
+                            name, id
+                        ),
+                        false,
+                    );
+                }
+            };
+
+            let ctx_id = ctx.id();
+            let ids = CardIds {
+                jobs: format!("{}jobs", ctx_id),
+                gear: format!("{}gear", ctx_id),
+                free_company: fc_id.as_ref().map(|_| format!("{}free-company", ctx_id)),
+                back: format!("{}back", ctx_id),
+            };
+
             ctx.send(|b| {
                 b.embed(|e| {
-                    e.title(title)
-                        .description(format!(
-                            "Lodestone ID: `{:?}`\n```{}```",
-                            character.id.0, character.bio
-                        ))
-                        .url(format!(
-                            "https://na.finalfantasyxiv.com/lodestone/character/{:?}",
-                            character.id.0
-                        ))
-                        .thumbnail(character.avatar)
-                        .field(
-                            "information",
-                            format!(
-                                "{:?} {:?}\n{:?}",
-                                character.tribe, character.race, character.gender
-                            ),
-                            true,
-                        )
-                        .field("city-state", format!("{:?}", character.town), true)
-                        .field("nameday", character.nameday, false)
-                        .footer(|f| f.text(format!("world: {}", character.world)))
+                    card(e);
+                    e
                 })
+                .components(|c| card_components(ctx, c, &ids))
             })
             .await?;
+
+            while let Some(press) = serenity::CollectComponentInteraction::new(ctx)
+                .filter(move |press| press.data.custom_id.starts_with(&ctx_id.to_string()))
+                .timeout(std::time::Duration::from_secs(60))
+                .await
+            {
+                if press.data.custom_id == ids.back {
+                    press
+                        .create_interaction_response(ctx, |b| {
+                            b.kind(serenity::InteractionResponseType::UpdateMessage)
+                                .interaction_response_data(|b| {
+                                    b.embed(|e| {
+                                        card(e);
+                                        e
+                                    })
+                                    .components(|c| card_components(ctx, c, &ids))
+                                })
+                        })
+                        .await?;
+
+                    continue;
+                }
+
+                if let (Some(button), Some(id)) = (&ids.free_company, &fc_id) {
+                    if press.data.custom_id == *button {
+                        press.defer(ctx).await?;
+                        let fc = ctx.data().content.free_company(id, language).await;
+
+                        press
+                            .edit_original_interaction_response(ctx, |b| {
+                                b.embed(|e| match fc.as_ref().map(|r| &r.free_company) {
+                                    Ok(Some(fc)) => free_company_embed(ctx, e, fc),
+                                    _ => e.title(tr!(ctx, "xivapi-error")),
+                                })
+                                .components(|c| back_components(ctx, c, &ids))
+                            })
+                            .await?;
+
+                        continue;
+                    }
+                }
+
+                let view: View = if press.data.custom_id == ids.jobs {
+                    jobs_embed
+                } else if press.data.custom_id == ids.gear {
+                    gear_embed
+                } else {
+                    continue;
+                };
+
+                // fetching the full character can take longer than Discord's response window.
+                press.defer(ctx).await?;
+                let row = ctx
+                    .data()
+                    .content
+                    .character_details(character.id.0, language)
+                    .await;
+
+                press
+                    .edit_original_interaction_response(ctx, |b| {
+                        b.embed(|e| match &row {
+                            Ok(row) => view(ctx, e, row),
+                            Err(_) => e.title(tr!(ctx, "xivapi-error")),
+                        })
+                        .components(|c| back_components(ctx, c, &ids))
+                    })
+                    .await?;
+            }
         }
-        Err(_) => {
+        _ => {
             ctx.send(|b| {
                 b.embed(|e| {
-                    e.title("couldn't find your character!")
-                        .description(format!(
-                            "Kotonya couldn't find the character with the given {}, nya!",
-                            method
-                        ))
+                    e.title(tr!(ctx, "character-not-found"))
+                        .description(tr!(ctx, &format!("character-not-found.{}", method)))
                 })
             })
             .await?;
@@ -72,46 +421,86 @@ async fn return_embed(
     Ok(())
 }
 
+/// add an unverified link, making it the user's main if it's their first, and hand out a
+/// one-time code for the character's Lodestone bio.
+async fn start_link(
+    ctx: Context<'_>,
+    con: &mut redis::aio::Connection,
+    id: u64,
+    name: &str,
+) -> Result<(), Error> {
+    let code = format!(
+        "kotonya-{}",
+        Alphanumeric.sample_string(&mut rand::thread_rng(), 8)
+    );
+
+    let user = ctx.author().id;
+
+    link::add(con, user, id, name).await?;
+    let _: () = con
+        .set_ex(
+            link::verification_key(user, id),
+            &code,
+            link::VERIFICATION_TTL,
+        )
+        .await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "link-successful"))
+                .description(tr!(
+                    ctx,
+                    "link-successful.description",
+                    user: ctx.author().name.clone(),
+                    character: name.to_string(),
+                ))
+                .field(
+                    tr!(ctx, "link-verification"),
+                    tr!(ctx, "link-verification.description", code: code.clone()),
+                    false,
+                )
+        })
+    })
+    .await?;
+
+    link::changed(ctx).await;
+
+    Ok(())
+}
+
+#[poise::command(
+    slash_command,
+    subcommands("link_character", "verify", "list", "set_main"),
+    subcommand_required
+)]
+pub async fn link(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
 /// link your character to Kotonya.
-#[poise::command(slash_command)]
-pub async fn link(
+#[poise::command(slash_command, rename = "character")]
+pub async fn link_character(
     ctx: Context<'_>,
     #[description = "your character name or lodestone id"] input: String,
 ) -> Result<(), Error> {
-    let api = &ctx.data().api;
     let con = &mut ctx.data().client.get_async_connection().await?;
 
     ctx.defer().await?;
 
     match input.parse::<u64>() {
         Ok(t) => {
-            let response = api.character(t.into()).send().await;
-
-            match response {
-                Ok(r) => {
-                    let character = r.character.unwrap();
+            let response = ctx.data().content.character(t, Language::English).await;
 
-                    con.set(&ctx.author().id.to_string(), character.id.to_string())
-                        .await?;
-
-                    ctx.send(|b| {
-                        b.embed(|e| {
-                            e.title("link successful!").description(format!(
-                                "successfully linked `{}` with `{}`!",
-                                ctx.author().name,
-                                character.name,
-                            ))
-                        })
-                    })
-                    .await?;
+            match response.map(|r| r.character) {
+                Ok(Some(character)) => {
+                    start_link(ctx, con, character.id.0, &character.name).await?;
                 }
 
-                Err(_) => {
+                _ => {
                     ctx.send(|b| {
                         b.embed(|e| {
-                            e.title("couldn't find your character!").description(
-                                "Kotonya couldn't find a character with the given ID, nya!",
-                            )
+                            e.title(tr!(ctx, "character-not-found"))
+                                .description(tr!(ctx, "character-not-found.id"))
                         })
                     })
                     .await?;
@@ -120,16 +509,15 @@ pub async fn link(
         }
 
         Err(_) => {
-            let response = api.character_search().name(&input).send().await;
+            let response = ctx.data().content.character_search(&input, &[]).await;
 
             match response {
-                Ok(r) => {
-                    if r.results.is_empty() {
+                Ok(hits) => {
+                    if hits.is_empty() {
                         ctx.send(|b| {
                             b.embed(|e| {
-                                e.title("couldn't find your character!").description(
-                                    "Kotonya couldn't find a character with the given name, nya!",
-                                )
+                                e.title(tr!(ctx, "character-not-found"))
+                                    .description(tr!(ctx, "character-not-found.name"))
                             })
                         })
                         .await?;
@@ -137,27 +525,20 @@ pub async fn link(
                         return Ok(());
                     }
 
-                    let id = r.results[0].id;
-                    let character = api.character(id.into()).send().await?.character.unwrap();
+                    let choices: Vec<_> = hits.iter().map(Choice::from).collect();
+                    let picked = chooser::choose(ctx, &choices, tr!(ctx, "character-pick")).await?;
 
-                    con.set(&ctx.author().id.to_string(), character.id.to_string())
-                        .await?;
-
-                    ctx.send(|b| {
-                        b.embed(|e| {
-                            e.title("link successful!").description(format!(
-                                "successfully linked `{}` with `{}`!",
-                                ctx.author().name,
-                                character.name,
-                            ))
-                        })
-                    })
-                    .await?;
+                    if let Some(hit) = picked.map(|i| &hits[i]) {
+                        start_link(ctx, con, hit.id, &hit.name).await?;
+                    }
                 }
 
                 Err(e) => {
                     ctx.send(|b| {
-                        b.embed(|em| em.title("xivapi error!").description(format!("{:#?}", e)))
+                        b.embed(|em| {
+                            em.title(tr!(ctx, "xivapi-error"))
+                                .description(format!("{:#?}", e))
+                        })
                     })
                     .await?;
                 }
@@ -168,90 +549,1075 @@ pub async fn link(
     Ok(())
 }
 
-#[poise::command(slash_command, subcommands("name", "id", "_self"), subcommand_required)]
+/// verify your pending links once their codes are in your characters' Lodestone bios.
+#[poise::command(slash_command)]
+pub async fn verify(ctx: Context<'_>) -> Result<(), Error> {
+    let user = ctx.author().id;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    ctx.defer().await?;
+
+    let mut pending = vec![];
+    for (id, name) in link::characters(con, user).await? {
+        let code: Option<String> = con.get(link::verification_key(user, &id)).await?;
+
+        if let Some(code) = code {
+            pending.push((id, name, code));
+        }
+    }
+
+    if pending.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "link-not-pending"))
+                    .description(tr!(ctx, "link-not-pending.description"))
+            })
+        })
+        .await?;
+        return Ok(());
+    }
+
+    let mut verified = vec![];
+    let mut failed = vec![];
+    for (id, name, code) in pending {
+        let character = ctx
+            .data()
+            .content
+            .character(&id, Language::English)
+            .await
+            .ok()
+            .and_then(|r| r.character);
+
+        // a character that can't be fetched can't have the code in its bio either.
+        if character.is_some_and(|c| c.bio.contains(&code)) {
+            let _: () = con.sadd(link::verified_key(user), &id).await?;
+            let _: () = con.del(link::verification_key(user, &id)).await?;
+
+            verified.push(tr!(ctx, "link-verified.description", character: name));
+        } else {
+            failed.push(tr!(
+                ctx,
+                "link-verify-failed.description",
+                character: name,
+                code: code
+            ));
+        }
+    }
+
+    let changed = !verified.is_empty();
+    let title = if changed {
+        tr!(ctx, "link-verified")
+    } else {
+        tr!(ctx, "link-verify-failed")
+    };
+    let mut lines = verified;
+    if !failed.is_empty() {
+        lines.extend(failed);
+        lines.push(tr!(ctx, "link-verify-failed.retry"));
+    }
+
+    ctx.send(|b| b.embed(|e| e.title(title).description(lines.join("\n"))))
+        .await?;
+
+    if changed {
+        link::changed(ctx).await;
+    }
+
+    Ok(())
+}
+
+/// list the characters you've linked.
+#[poise::command(slash_command)]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    let user = ctx.author().id;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let characters = link::characters(con, user).await?;
+    let main: Option<String> = con.get(link::main_key(user)).await?;
+
+    if characters.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-not-linked"))
+                    .description(tr!(ctx, "character-not-linked.description"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    let mut lines = vec![];
+    for (id, name) in &characters {
+        let verified = link::is_verified(con, user, id).await?;
+
+        lines.push(format!(
+            "{}{} (`{}`){}",
+            if main.as_ref() == Some(id) {
+                "⭐ "
+            } else {
+                ""
+            },
+            name,
+            id,
+            if verified { "" } else { " ⚠️" }
+        ));
+    }
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "linked-characters"))
+                .description(lines.join("\n"))
+                .footer(|f| f.text(tr!(ctx, "linked-characters.footer")))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// pick the character `self` commands show by default.
+#[poise::command(slash_command, rename = "main")]
+pub async fn set_main(
+    ctx: Context<'_>,
+    #[description = "the linked character to make your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    character: String,
+) -> Result<(), Error> {
+    let user = ctx.author().id;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    match link::resolve(con, user, Some(&character)).await? {
+        Some(id) => {
+            let _: () = con.set(link::main_key(user), id).await?;
+
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "link-main-updated")).description(tr!(
+                        ctx,
+                        "link-main-updated.description",
+                        character: character.clone()
+                    ))
+                })
+            })
+            .await?;
+
+            link::changed(ctx).await;
+        }
+        None => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-linked")).description(tr!(
+                        ctx,
+                        "character-not-linked.alt",
+                        alt: character.clone()
+                    ))
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// unlink one of your characters from Kotonya.
+#[poise::command(slash_command)]
+pub async fn unlink(
+    ctx: Context<'_>,
+    #[description = "the linked character to unlink"]
+    #[autocomplete = "crate::link::autocomplete"]
+    character: String,
+) -> Result<(), Error> {
+    let user = ctx.author().id;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    match link::resolve(con, user, Some(&character)).await? {
+        Some(id) => {
+            link::unlink(con, user, &id).await?;
+
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "unlink-successful")).description(tr!(
+                        ctx,
+                        "unlink-successful.description",
+                        character: character.clone()
+                    ))
+                })
+            })
+            .await?;
+
+            link::changed(ctx).await;
+        }
+        None => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-linked")).description(tr!(
+                        ctx,
+                        "character-not-linked.alt",
+                        alt: character.clone()
+                    ))
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+#[poise::command(
+    slash_command,
+    subcommands(
+        "name",
+        "id",
+        "_self",
+        "user",
+        "jobs",
+        "gear",
+        "collection",
+        "compare",
+        "history"
+    ),
+    subcommand_required
+)]
 pub async fn character(_: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
 
 /// fetch your linked character.
 #[poise::command(slash_command, rename = "self")]
-pub async fn _self(ctx: Context<'_>) -> Result<(), Error> {
-    let id = &ctx.author().id.to_string();
+pub async fn _self(
+    ctx: Context<'_>,
+    #[description = "one of your linked alts to show instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    let user = ctx.author().id;
     let con = &mut ctx.data().client.get_async_connection().await?;
-    let result: Result<String, RedisError> = con.get(id).await;
+    let result = link::resolve(con, user, alt.as_deref()).await?;
 
     ctx.defer().await?;
 
     match result {
-        Ok(t) => {
-            let api = &ctx.data().api;
-            let response = api.character(t.parse::<u64>().unwrap().into()).send().await;
+        Some(t) => {
+            let verified = link::is_verified(con, user, &t).await?;
+            let language = language::resolve(ctx, language).await;
+            let response = ctx.data().content.character(&t, language).await;
 
-            return_embed("ID", response, &ctx).await?;
+            return_embed("id", response, !verified, language, ctx).await?;
+        }
+        None => {
+            let description = match &alt {
+                Some(alt) => tr!(ctx, "character-not-linked.alt", alt: alt.clone()),
+                None => tr!(ctx, "character-not-linked.description"),
+            };
+
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-linked"))
+                        .description(description)
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// the ID of the invoking user's main, or of the linked alt named by `alt`, telling them
+/// if there's no such character.
+async fn linked_character(ctx: Context<'_>, alt: Option<&str>) -> Result<Option<String>, Error> {
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let result = link::resolve(con, ctx.author().id, alt).await?;
+
+    if result.is_none() {
+        let description = match alt {
+            Some(alt) => tr!(ctx, "character-not-linked.alt", alt: alt.to_string()),
+            None => tr!(ctx, "character-not-linked.description"),
+        };
+
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-not-linked"))
+                    .description(description)
+            })
+        })
+        .await?;
+    }
+
+    Ok(result)
+}
+
+/// show one of the detail views of the invoking user's main or a linked alt.
+async fn show_view(
+    ctx: Context<'_>,
+    alt: Option<String>,
+    language: Option<Language>,
+    view: View,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let id = match linked_character(ctx, alt.as_deref()).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    let language = language::resolve(ctx, language).await;
+    let row = ctx.data().content.character_details(&id, language).await;
+
+    match row {
+        Ok(row) => {
+            ctx.send(|b| b.embed(|e| view(ctx, e, &row))).await?;
         }
         Err(_) => {
-            ctx.send(|b| b.embed(|e| e
-                .title("couldn't fetch your character!")
-                .description("you don't have a character linked to your Discord account. please use `/link <name/id>` to link your character!"))
-            ).await?;
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-found"))
+                        .description(tr!(ctx, "character-not-found.id"))
+                })
+            })
+            .await?;
         }
     }
 
     Ok(())
 }
 
-/// fetch a character by their name and world.
+/// show the class and job levels of your linked character.
 #[poise::command(slash_command)]
-pub async fn name(
+pub async fn jobs(
     ctx: Context<'_>,
-    #[description = "the character's name"] name: String,
-    #[description = "the character's world"] world: String,
+    #[description = "one of your linked alts to show instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
 ) -> Result<(), Error> {
-    let api = &ctx.data().api;
-    let world = World::from_str(&world);
+    show_view(ctx, alt, language, jobs_embed).await
+}
+
+/// show the gear your linked character has equipped.
+#[poise::command(slash_command)]
+pub async fn gear(
+    ctx: Context<'_>,
+    #[description = "one of your linked alts to show instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    show_view(ctx, alt, language, gear_embed).await
+}
+
+/// one of a character's collections, to list in full.
+#[derive(Debug, Clone, Copy, poise::ChoiceParameter)]
+pub enum Collection {
+    #[name = "mounts"]
+    Mounts,
+    #[name = "minions"]
+    Minions,
+    #[name = "achievements"]
+    Achievements,
+}
+
+/// a character's achievements, most recent first, as `(name, unix timestamp)` pairs.
+fn achievements(row: &Value) -> Vec<(String, u64)> {
+    let mut achievements: Vec<_> = row
+        .pointer("/Achievements/List")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|a| {
+            let name = field(a, "Name").or_else(|| field(a, "ID").map(|id| format!("#{}", id)))?;
 
+            Some((name, a["Date"].as_u64().unwrap_or(0)))
+        })
+        .collect();
+    achievements.sort_by(|a, b| b.1.cmp(&a.1));
+
+    achievements
+}
+
+/// the names in one of a character's collections (`Mounts` or `Minions`), sorted.
+fn collection_names(row: &Value, collection: &str) -> Vec<String> {
+    let mut names: Vec<_> = row[collection]
+        .as_array()
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|m| field(m, "Name"))
+        .collect();
+    names.sort();
+
+    names
+}
+
+/// how many of a collection's entries a character owns, out of how many there are.
+fn collection_count(ctx: Context<'_>, owned: usize, total: Option<u64>) -> String {
+    match total {
+        Some(total) => tr!(
+            ctx,
+            "character-collection-count",
+            owned: owned,
+            total: total,
+            percent: owned as u64 * 100 / total
+        ),
+        None => owned.to_string(),
+    }
+}
+
+/// show your linked character's mounts, minions and achievements.
+#[poise::command(slash_command)]
+pub async fn collection(
+    ctx: Context<'_>,
+    #[description = "one of your linked alts to show instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+    #[description = "a collection to list in full"] list: Option<Collection>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
     ctx.defer().await?;
 
-    match world {
-        Ok(w) => {
-            let response = api
-                .character_search()
-                .name(&name)
-                .server(w)
-                .send()
-                .await
-                .unwrap();
+    let id = match linked_character(ctx, alt.as_deref()).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    let language = language::resolve(ctx, language).await;
+    let row = match ctx
+        .data()
+        .content
+        .character_collections(&id, language)
+        .await
+    {
+        Ok(row) => row,
+        Err(_) => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-found"))
+                        .description(tr!(ctx, "character-not-found.id"))
+                })
+            })
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    let name = field(&row, "Character.Name").unwrap_or_default();
+    let public = row["AchievementsPublic"].as_bool().unwrap_or(false);
+    let mounts = collection_names(&row, "Mounts");
+    let minions = collection_names(&row, "Minions");
+    let achievements = achievements(&row);
+
+    if let Some(list) = list {
+        let (message, lines) = match list {
+            Collection::Mounts => ("character-collection-list.mounts", mounts),
+            Collection::Minions => ("character-collection-list.minions", minions),
+            Collection::Achievements => (
+                "character-collection-list.achievements",
+                achievements
+                    .iter()
+                    .map(|(name, date)| format!("{} — <t:{}:d>", name, date))
+                    .collect(),
+            ),
+        };
+
+        return search::paginate_list(ctx, tr!(ctx, message, name: name), &lines).await;
+    }
+
+    let mount_total = search::index_total(ctx, "mount").await;
+    let minion_total = search::index_total(ctx, "minion").await;
+
+    let recent = achievements
+        .iter()
+        .take(5)
+        .map(|(name, date)| format!("{} — <t:{}:d>", name, date))
+        .collect::<Vec<_>>();
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "character-collection-summary", name: name.clone()))
+                .field(
+                    tr!(ctx, "search-kind-mount.plural"),
+                    collection_count(ctx, mounts.len(), mount_total),
+                    true,
+                )
+                .field(
+                    tr!(ctx, "search-kind-minion.plural"),
+                    collection_count(ctx, minions.len(), minion_total),
+                    true,
+                );
+
+            if let Some(avatar) = field(&row, "Character.Avatar") {
+                e.thumbnail(avatar);
+            }
+
+            if public {
+                e.field(
+                    tr!(ctx, "character-collection-summary.points"),
+                    field(&row, "Achievements.Points").unwrap_or_else(|| "0".to_string()),
+                    true,
+                );
+
+                if !recent.is_empty() {
+                    e.field(
+                        tr!(ctx, "character-collection-summary.recent"),
+                        recent.join("\n"),
+                        false,
+                    );
+                }
+            } else {
+                e.field(
+                    tr!(ctx, "search-kind-achievement.plural"),
+                    tr!(ctx, "character-collection-summary.private"),
+                    false,
+                );
+            }
+
+            e
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// the difference between two values, bolded unless they're equal.
+fn delta(a: i64, b: i64) -> String {
+    match a - b {
+        0 => "±0".to_string(),
+        d if d > 0 => format!("**+{}**", d),
+        d => format!("**{}**", d),
+    }
+}
+
+/// two values side by side, followed by their difference.
+fn versus(a: i64, b: i64) -> String {
+    format!("{} · {} ({})", a, b, delta(a, b))
+}
+
+/// a class or job's name and level on a character, by the job's `ClassJob` ID.
+fn job_level(row: &Value, id: u64) -> (Option<String>, u64) {
+    let job = row
+        .pointer("/Character/ClassJobs")
+        .and_then(Value::as_array)
+        .and_then(|jobs| jobs.iter().find(|j| j["JobID"].as_u64() == Some(id)));
+
+    match job {
+        Some(job) => (
+            field(job, "UnlockedState.Name"),
+            job["Level"].as_u64().unwrap_or(0),
+        ),
+        None => (None, 0),
+    }
+}
+
+/// two characters' item levels, collections and job levels side by side.
+fn compare_embed<'a>(
+    ctx: Context<'_>,
+    e: &'a mut serenity::CreateEmbed,
+    a: &Value,
+    b: &Value,
+) -> &'a mut serenity::CreateEmbed {
+    let name = |row: &Value| {
+        format!(
+            "**{}** ({})",
+            field(row, "Character.Name").unwrap_or_default(),
+            field(row, "Character.Server").unwrap_or_default()
+        )
+    };
+    let item_level = |row: &Value| {
+        average_item_level(
+            row.pointer("/Character/GearSet/Gear")
+                .unwrap_or(&Value::Null),
+        ) as i64
+    };
+    let count = |row: &Value, collection: &str| collection_names(row, collection).len() as i64;
+    let points = |row: &Value| {
+        row.pointer("/Achievements/Points")
+            .and_then(Value::as_i64)
+            .unwrap_or(0)
+    };
+
+    e.title(tr!(ctx, "character-comparison"))
+        .description(format!("{}\n{}", name(a), name(b)))
+        .field(
+            tr!(ctx, "character-comparison.item-level"),
+            versus(item_level(a), item_level(b)),
+            true,
+        )
+        .field(
+            tr!(ctx, "search-kind-mount.plural"),
+            versus(count(a, "Mounts"), count(b, "Mounts")),
+            true,
+        )
+        .field(
+            tr!(ctx, "search-kind-minion.plural"),
+            versus(count(a, "Minions"), count(b, "Minions")),
+            true,
+        )
+        .field(
+            tr!(ctx, "character-collection-summary.points"),
+            versus(points(a), points(b)),
+            true,
+        );
+
+    for role in job::ROLES {
+        let lines: Vec<_> = role
+            .jobs
+            .iter()
+            .filter_map(|id| {
+                let (a_name, a_level) = job_level(a, *id);
+                let (b_name, b_level) = job_level(b, *id);
+
+                if a_level == 0 && b_level == 0 {
+                    return None;
+                }
+
+                Some(format!(
+                    "**{}** {}",
+                    a_name.or(b_name)?,
+                    versus(a_level as i64, b_level as i64)
+                ))
+            })
+            .collect();
+
+        if !lines.is_empty() {
+            e.field(tr!(ctx, role.name), lines.join("\n"), true);
+        }
+    }
+
+    e
+}
+
+/// the Lodestone ID of a character given by name, Lodestone ID or a linked user's mention,
+/// telling the user if there's no such character.
+async fn find_character(ctx: Context<'_>, input: &str) -> Result<Option<String>, Error> {
+    let input = input.trim();
+    let mention = input
+        .strip_prefix("<@")
+        .and_then(|s| s.strip_suffix('>'))
+        .map(|s| s.trim_start_matches('!'))
+        .and_then(|s| s.parse::<u64>().ok());
+
+    if let Some(user) = mention {
+        let con = &mut ctx.data().client.get_async_connection().await?;
+
+        if user != ctx.author().id.0 && link::is_hidden(con, user).await? {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-hidden")).description(
+                        tr!(ctx, "character-hidden.description", user: input.to_string()),
+                    )
+                })
+            })
+            .await?;
+
+            return Ok(None);
+        }
+
+        let result = link::resolve(con, user, None).await?;
+
+        if result.is_none() {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-linked"))
+                        .description(tr!(ctx, "character-not-linked.user", user: input.to_string()))
+                })
+            })
+            .await?;
+        }
+
+        return Ok(result);
+    }
+
+    if input.parse::<u64>().is_ok() {
+        return Ok(Some(input.to_string()));
+    }
+
+    let hits = ctx.data().content.character_search(input, &[]).await?;
+
+    if hits.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-not-found"))
+                    .description(tr!(ctx, "character-not-found.name"))
+            })
+        })
+        .await?;
+
+        return Ok(None);
+    }
 
-            let id = response.results[0].id;
-            let character = api.character(id.into()).send().await;
+    let choices: Vec<_> = hits.iter().map(Choice::from).collect();
+    let picked = chooser::choose(ctx, &choices, tr!(ctx, "character-pick")).await?;
 
-            return_embed("name", character, &ctx).await?
+    Ok(picked.map(|i| hits[i].id.to_string()))
+}
+
+/// compare two characters side by side.
+#[poise::command(slash_command)]
+pub async fn compare(
+    ctx: Context<'_>,
+    #[description = "a character name, Lodestone ID or a linked user's mention"] first: String,
+    #[description = "a character name, Lodestone ID or a linked user's mention"] second: String,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let first = match find_character(ctx, &first).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+    let second = match find_character(ctx, &second).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    let language = language::resolve(ctx, language).await;
+    let content = &ctx.data().content;
+    let rows = futures::try_join!(
+        content.character_collections(&first, language),
+        content.character_collections(&second, language),
+    );
+
+    match rows {
+        Ok((a, b)) => {
+            ctx.send(|r| r.embed(|e| compare_embed(ctx, e, &a, &b)))
+                .await?;
         }
         Err(_) => {
-            ctx.send(|b| b.embed(|e| e.title("invalid world!"))).await?;
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-found"))
+                        .description(tr!(ctx, "character-not-found.id"))
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// the characters a sparkline is drawn with, from lowest to highest.
+const SPARKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// how many snapshots the history view charts.
+const HISTORY_LENGTH: usize = 30;
+
+/// a sparkline charting values over time.
+fn sparkline(values: &[u64]) -> String {
+    let min = values.iter().copied().min().unwrap_or(0);
+    let max = values.iter().copied().max().unwrap_or(0);
+
+    values
+        .iter()
+        .map(|v| match max - min {
+            0 => SPARKS[0],
+            range => SPARKS[((v - min) * 7 / range) as usize],
+        })
+        .collect()
+}
+
+/// a chart of one of a character's stats, with how much it changed.
+fn history_field(values: &[u64]) -> String {
+    let first = values.first().copied().unwrap_or(0);
+    let last = values.last().copied().unwrap_or(0);
+
+    format!(
+        "`{}`\n{} → {} ({})",
+        sparkline(values),
+        first,
+        last,
+        delta(last as i64, first as i64)
+    )
+}
+
+/// chart your linked character's progress over time.
+#[poise::command(slash_command)]
+pub async fn history(
+    ctx: Context<'_>,
+    #[description = "one of your linked alts to show instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+) -> Result<(), Error> {
+    let id = match linked_character(ctx, alt.as_deref()).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    let user = ctx.author().id;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let mut snapshots = history::snapshots(con, user, &id).await?;
+    let name = link::characters(con, user)
+        .await?
+        .into_iter()
+        .find(|(c, _)| *c == id)
+        .map_or(id.clone(), |(_, name)| name);
+
+    if snapshots.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-progress", name: name.as_str()))
+                    .description(tr!(ctx, "character-progress.empty"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    snapshots.drain(..snapshots.len().saturating_sub(HISTORY_LENGTH));
+    let values =
+        |stat: fn(&history::Snapshot) -> u64| -> Vec<u64> { snapshots.iter().map(stat).collect() };
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "character-progress", name: name.as_str()))
+                .description(tr!(
+                    ctx,
+                    "character-progress.description",
+                    since: format!("<t:{}:D>", snapshots[0].time),
+                    count: snapshots.len()
+                ))
+                .field(
+                    tr!(ctx, "character-progress.item-level"),
+                    history_field(&values(|s| s.item_level)),
+                    false,
+                )
+                .field(
+                    tr!(ctx, "character-progress.total-levels"),
+                    history_field(&values(history::Snapshot::total_levels)),
+                    false,
+                )
+                .field(
+                    tr!(ctx, "character-collection-summary.points"),
+                    history_field(&values(|s| s.achievement_points)),
+                    false,
+                )
+                .field(
+                    tr!(ctx, "search-kind-mount.plural"),
+                    history_field(&values(|s| s.mounts as u64)),
+                    true,
+                )
+                .field(
+                    tr!(ctx, "search-kind-minion.plural"),
+                    history_field(&values(|s| s.minions as u64)),
+                    true,
+                )
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// show a member's main character, unless they've hidden it from others.
+async fn show_member(ctx: Context<'_>, member: &serenity::User) -> Result<(), Error> {
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    ctx.defer().await?;
+
+    if member.id != ctx.author().id && link::is_hidden(con, member.id).await? {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-hidden")).description(tr!(
+                    ctx,
+                    "character-hidden.description",
+                    user: member.name.clone()
+                ))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    match link::resolve(con, member.id, None).await? {
+        Some(t) => {
+            let verified = link::is_verified(con, member.id, &t).await?;
+            let language = language::resolve(ctx, None).await;
+            let response = ctx.data().content.character(&t, language).await;
+
+            return_embed("id", response, !verified, language, ctx).await?;
+        }
+        None => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "character-not-linked")).description(tr!(
+                        ctx,
+                        "character-not-linked.user",
+                        user: member.name.clone()
+                    ))
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// fetch another member's linked character.
+#[poise::command(slash_command)]
+pub async fn user(
+    ctx: Context<'_>,
+    #[description = "the member whose character to show"] member: serenity::User,
+) -> Result<(), Error> {
+    show_member(ctx, &member).await
+}
+
+#[poise::command(context_menu_command = "View FFXIV character")]
+pub async fn view_character(ctx: Context<'_>, member: serenity::User) -> Result<(), Error> {
+    show_member(ctx, &member).await
+}
+
+/// fetch a character by their name, optionally narrowed to a world, data center or region.
+#[poise::command(slash_command)]
+pub async fn name(
+    ctx: Context<'_>,
+    #[description = "the character's name"]
+    #[autocomplete = "autocomplete_name"]
+    name: String,
+    #[description = "the character's world, data center or region"]
+    #[autocomplete = "crate::world::autocomplete"]
+    world: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    let worlds = match world.as_deref().map(world::scope) {
+        Some(Some(worlds)) => worlds,
+        Some(None) => {
+            ctx.send(|b| b.embed(|e| e.title(tr!(ctx, "invalid-world"))))
+                .await?;
             return Ok(());
         }
+        None => vec![],
+    };
+
+    ctx.defer().await?;
+
+    let language = language::resolve(ctx, language).await;
+    let hits = ctx.data().content.character_search(&name, &worlds).await?;
+
+    if hits.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "character-not-found"))
+                    .description(tr!(ctx, "character-not-found.name"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    let choices: Vec<_> = hits.iter().map(Choice::from).collect();
+
+    if let Some(i) = chooser::choose(ctx, &choices, tr!(ctx, "character-pick")).await? {
+        let character = ctx.data().content.character(hits[i].id, language).await;
+
+        return_embed("name", character, false, language, ctx).await?;
     }
 
     Ok(())
 }
 
+/// autocompletes a character's name from a Lodestone search on every world.
+async fn autocomplete_name(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let partial = partial.trim().to_lowercase();
+    if partial.is_empty() {
+        return vec![];
+    }
+
+    let content = &ctx.data().content;
+
+    search::cached_suggestions(ctx, &format!("character:{}", partial), async {
+        let mut names: Vec<_> = content
+            .character_search(&partial, &[])
+            .await?
+            .into_iter()
+            .map(|hit| hit.name)
+            .collect();
+        names.sort();
+        names.dedup();
+        names.truncate(25);
+
+        Ok::<_, reqwest::Error>(names)
+    })
+    .await
+}
+
+/// a Lodestone ID argument as a number, telling the user if it isn't one.
+///
+/// IDs end up in XIVAPI request paths, so anything else is turned away before it gets there.
+pub async fn lodestone_id(ctx: Context<'_>, id: &str) -> Result<Option<u64>, Error> {
+    match id.trim().parse::<u64>() {
+        Ok(id) => Ok(Some(id)),
+        Err(_) => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "invalid-id"))
+                        .description(tr!(ctx, "invalid-id.description", id: id))
+                })
+            })
+            .await?;
+
+            Ok(None)
+        }
+    }
+}
+
 /// fetch a character by their Lodestone ID.
 #[poise::command(slash_command)]
 pub async fn id(
     ctx: Context<'_>,
     #[description = "the character's Lodestone ID"] id: String,
+    #[description = "the language to show game data in"] language: Option<Language>,
 ) -> Result<(), Error> {
-    let api = &ctx.data().api;
-
     ctx.defer().await?;
 
-    let response = api
-        .character(id.parse::<u64>().unwrap().into())
-        .send()
-        .await;
+    let id = match lodestone_id(ctx, &id).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
 
-    return_embed("ID", response, &ctx).await?;
+    let language = language::resolve(ctx, language).await;
+    let response = ctx.data().content.character(id, language).await;
+
+    return_embed("id", response, false, language, ctx).await?;
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn counts_only_two_handed_weapons_twice() {
+        let mut gear = json!({
+            "MainHand": { "Item": { "LevelItem": 600, "EquipSlotCategory": { "OffHand": -1 } } },
+            "Head": { "Item": { "LevelItem": 600 } },
+        });
+        assert_eq!(average_item_level(&gear), 150);
+
+        gear["MainHand"]["Item"]["EquipSlotCategory"]["OffHand"] = json!(0);
+        assert_eq!(average_item_level(&gear), 100);
+    }
+
+    #[test]
+    fn sparkline_spans_every_height() {
+        assert_eq!(sparkline(&[0, 1, 2, 3, 4, 5, 6, 7]), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[10, 80, 45]), "▁█▄");
+    }
+
+    #[test]
+    fn sparkline_of_a_flat_or_empty_history() {
+        assert_eq!(sparkline(&[42, 42, 42]), "▁▁▁");
+        assert_eq!(sparkline(&[]), "");
+    }
+}
@@ -1,44 +1,68 @@
-use crate::{Context, Error};
-use redis::{AsyncCommands, RedisError};
-use std::str::FromStr;
-use xivapi::{
-    models::free_company::FreeCompanyResult,
-    prelude::{Builder, World},
+use crate::{
+    chooser::{self, Choice},
+    commands::{character, search},
+    content::field,
+    free_company,
+    i18n::tr,
+    language::{self, Language},
+    link, world, Context, Error,
 };
+use poise::serenity_prelude as serenity;
+use redis::AsyncCommands;
+use std::collections::HashMap;
+use tracing::warn;
+use xivapi::models::free_company::{FreeCompany, FreeCompanyResult};
+
+/// renders a free company's card.
+pub fn free_company_embed<'a>(
+    ctx: Context<'_>,
+    e: &'a mut serenity::CreateEmbed,
+    fc: &FreeCompany,
+) -> &'a mut serenity::CreateEmbed {
+    e.title(format!("{} «{}»", fc.name, fc.tag))
+        .description(format!(
+            "{}\n```{}```",
+            tr!(ctx, "free-company-lodestone-id", id: fc.id.0.to_string()),
+            fc.slogan
+        ))
+        .url(format!(
+            "https://na.finalfantasyxiv.com/lodestone/freecompany/{}",
+            fc.id.0
+        ))
+        .thumbnail(fc.crest[1].clone())
+        .field(tr!(ctx, "free-company-formed"), &fc.formed, true)
+        .field(
+            tr!(ctx, "free-company-grand-company"),
+            &fc.grand_company,
+            true,
+        )
+        .field(
+            tr!(ctx, "free-company-server"),
+            format!("{}", fc.server),
+            true,
+        )
+        .field(
+            tr!(ctx, "free-company-active-members"),
+            &fc.active_member_count,
+            true,
+        )
+}
 
 async fn return_embed(
     method: &str,
-    response: Result<FreeCompanyResult, xivapi::error::Error>,
+    response: Result<FreeCompanyResult, reqwest::Error>,
     ctx: &Context<'_>,
 ) -> Result<(), Error> {
-    match response {
-        Ok(r) => {
-            let fc = r.free_company.unwrap();
-            ctx.send(|b| {
-                b.embed(|e| {
-                    e.title(format!("{} «{}»", fc.name, fc.tag))
-                        .description(format!("Lodestone ID: `{}`\n```{}```", fc.id.0, fc.slogan))
-                        .url(format!(
-                            "https://na.finalfantasyxiv.com/lodestone/freecompany/{}",
-                            fc.id.0
-                        ))
-                        .thumbnail(fc.crest[1].clone())
-                        .field("formed", fc.formed, true)
-                        .field("grand company", fc.grand_company, true)
-                        .field("server", format!("{}", fc.server), true)
-                        .field("active member count", fc.active_member_count, true)
-                })
-            })
-            .await?;
+    match response.map(|r| r.free_company) {
+        Ok(Some(fc)) => {
+            ctx.send(|b| b.embed(|e| free_company_embed(*ctx, e, &fc)))
+                .await?;
         }
-        Err(_) => {
+        _ => {
             ctx.send(|b| {
                 b.embed(|e| {
-                    e.title("couldn't find your free company!")
-                        .description(format!(
-                            "Kotonya couldn't find the free company with the specified {}, nya!",
-                            method
-                        ))
+                    e.title(tr!(*ctx, "free-company-not-found"))
+                        .description(tr!(*ctx, &format!("free-company-not-found.{}", method)))
                 })
             })
             .await?;
@@ -51,7 +75,7 @@ async fn return_embed(
 #[poise::command(
     rename = "freecompany",
     slash_command,
-    subcommands("name", "id", "_self"),
+    subcommands("name", "id", "_self", "members", "server", "bind", "unbind", "feed"),
     subcommand_required
 )]
 pub async fn free_company(_: Context<'_>) -> Result<(), Error> {
@@ -60,112 +84,560 @@ pub async fn free_company(_: Context<'_>) -> Result<(), Error> {
 
 /// fetch your linked character's free company.
 #[poise::command(rename = "self", slash_command)]
-pub async fn _self(ctx: Context<'_>) -> Result<(), Error> {
-    let id = &ctx.author().id.to_string();
+pub async fn _self(
+    ctx: Context<'_>,
+    #[description = "one of your linked alts to use instead of your main"]
+    #[autocomplete = "crate::link::autocomplete"]
+    alt: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
     let con = &mut ctx.data().client.get_async_connection().await?;
-    let result: Result<String, RedisError> = con.get(id).await;
+    let result = link::resolve(con, ctx.author().id, alt.as_deref()).await?;
 
     ctx.defer().await?;
 
     match result {
-        Ok(t) => {
-            let api = &ctx.data().api;
-            let response = api.character(t.parse::<u64>().unwrap().into()).send().await;
+        Some(t) => {
+            let response = ctx.data().content.character(&t, Language::English).await;
 
-            match response {
-                Ok(r) => {
-                    let fc = r.character.unwrap().free_company_id;
+            match response.map(|r| r.character) {
+                Ok(Some(character)) => {
+                    let fc = character.free_company_id;
 
                     match fc {
                         Some(f) => {
-                            let response = api.free_company(f).send().await;
+                            let language = language::resolve(ctx, language).await;
+                            let response = ctx.data().content.free_company(f.0, language).await;
 
-                            return_embed("ID", response, &ctx).await?;
+                            return_embed("id", response, &ctx).await?;
                         }
 
                         None => {
                             ctx.send(|b| {
                                 b.embed(|e| {
-                                    e.title("couldn't fetch your free company!").description(
-                                        "your linked character is currently not in a free company!",
-                                    )
+                                    e.title(tr!(ctx, "free-company-not-fetched"))
+                                        .description(tr!(
+                                            ctx,
+                                            "free-company-not-fetched.no-free-company"
+                                        ))
                                 })
                             })
                             .await?;
                         }
                     }
                 }
+                Ok(None) => {
+                    ctx.send(|b| {
+                        b.embed(|e| {
+                            e.title(tr!(ctx, "character-not-found"))
+                                .description(tr!(ctx, "character-not-found.id"))
+                        })
+                    })
+                    .await?;
+                }
                 Err(e) => {
                     ctx.send(|b| {
-                        b.embed(|em| em.title("xivapi error!").description(format!("{:#?}", e)))
+                        b.embed(|em| {
+                            em.title(tr!(ctx, "xivapi-error"))
+                                .description(format!("{:#?}", e))
+                        })
                     })
                     .await?;
                 }
             }
         }
-        Err(_) => {
-            ctx.send(|b| b.embed(|e| e
-                .title("couldn't fetch your free company!")
-                .description("you don't have a character linked to your Discord account. please use `/link <name/id>` to link your character!"))
-            ).await?;
+        None => {
+            let description = match &alt {
+                Some(alt) => tr!(ctx, "character-not-linked.alt", alt: alt.clone()),
+                None => tr!(ctx, "free-company-not-fetched.not-linked"),
+            };
+
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "free-company-not-fetched"))
+                        .description(description)
+                })
+            })
+            .await?;
         }
     }
 
     Ok(())
 }
 
-/// fetch a free company by its name and world.
+/// autocompletes a free company's name from a Lodestone search on every world.
+async fn autocomplete_name(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let partial = partial.trim().to_lowercase();
+    if partial.is_empty() {
+        return vec![];
+    }
+
+    let content = &ctx.data().content;
+
+    search::cached_suggestions(ctx, &format!("freecompany:{}", partial), async {
+        let mut names: Vec<_> = content
+            .free_company_search(&partial, &[])
+            .await?
+            .into_iter()
+            .map(|hit| hit.name)
+            .collect();
+        names.sort();
+        names.dedup();
+        names.truncate(25);
+
+        Ok::<_, reqwest::Error>(names)
+    })
+    .await
+}
+
+/// fetch a free company by its name, optionally narrowed to a world, data center or region.
 #[poise::command(slash_command)]
 pub async fn name(
     ctx: Context<'_>,
-    #[description = "the free company's name"] name: String,
-    #[description = "the free company's world"] world: String,
+    #[description = "the free company's name"]
+    #[autocomplete = "autocomplete_name"]
+    name: String,
+    #[description = "the free company's world, data center or region"]
+    #[autocomplete = "crate::world::autocomplete"]
+    world: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
 ) -> Result<(), Error> {
-    let api = &ctx.data().api;
-    let world = World::from_str(&world);
+    let worlds = match world.as_deref().map(world::scope) {
+        Some(Some(worlds)) => worlds,
+        Some(None) => {
+            ctx.send(|b| b.embed(|e| e.title(tr!(ctx, "invalid-world"))))
+                .await?;
+            return Ok(());
+        }
+        None => vec![],
+    };
+
+    ctx.defer().await?;
+
+    let language = language::resolve(ctx, language).await;
+    let hits = ctx
+        .data()
+        .content
+        .free_company_search(&name, &worlds)
+        .await?;
+
+    if hits.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "free-company-not-found"))
+                    .description(tr!(ctx, "free-company-not-found.name"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    let choices: Vec<_> = hits.iter().map(Choice::from).collect();
+
+    if let Some(i) = chooser::choose(ctx, &choices, tr!(ctx, "free-company-pick")).await? {
+        let fc = ctx.data().content.free_company(&hits[i].id, language).await;
+
+        return_embed("name", fc, &ctx).await?;
+    }
 
+    Ok(())
+}
+
+/// fetch a free company by its Lodestone ID.
+#[poise::command(slash_command)]
+pub async fn id(
+    ctx: Context<'_>,
+    #[description = "the free company's Lodestone ID"] id: String,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
     ctx.defer().await?;
 
-    match world {
-        Ok(w) => {
-            let response = api
-                .free_company_search()
-                .name(&name)
-                .server(w)
-                .send()
+    let id = match character::lodestone_id(ctx, &id).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    let language = language::resolve(ctx, language).await;
+    let response = ctx.data().content.free_company(id, language).await;
+
+    return_embed("id", response, &ctx).await?;
+
+    Ok(())
+}
+
+/// the Lodestone ID of your main character's free company, telling you if there's none.
+async fn linked_free_company(ctx: Context<'_>) -> Result<Option<String>, Error> {
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let description = match link::resolve(con, ctx.author().id, None).await? {
+        Some(id) => {
+            let row = match ctx
+                .data()
+                .content
+                .get("character", &id, Language::English)
                 .await
-                .unwrap();
+            {
+                Ok(row) => row,
+                // a deleted or private character can't be fetched.
+                Err(_) => {
+                    ctx.send(|b| {
+                        b.embed(|e| {
+                            e.title(tr!(ctx, "character-not-found"))
+                                .description(tr!(ctx, "character-not-found.id"))
+                        })
+                    })
+                    .await?;
 
-            let id = response.results[0].id;
-            let fc = api.free_company(id).send().await;
+                    return Ok(None);
+                }
+            };
 
-            return_embed("name", fc, &ctx).await?
+            match field(&row, "Character.FreeCompanyId") {
+                Some(fc) => return Ok(Some(fc)),
+                None => "free-company-not-fetched.no-free-company",
+            }
         }
+        None => "free-company-not-fetched.not-linked",
+    };
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "free-company-not-fetched"))
+                .description(tr!(ctx, description))
+        })
+    })
+    .await?;
+
+    Ok(None)
+}
+
+/// list your free company's members, or another's, and open their characters.
+#[poise::command(slash_command)]
+pub async fn members(
+    ctx: Context<'_>,
+    #[description = "another free company's Lodestone ID"] id: Option<String>,
+    #[description = "only list members of this rank"] rank: Option<String>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    ctx.defer().await?;
+
+    let id = match id {
+        Some(id) => match character::lodestone_id(ctx, &id).await? {
+            Some(id) => id.to_string(),
+            None => return Ok(()),
+        },
+        None => match linked_free_company(ctx).await? {
+            Some(id) => id,
+            None => return Ok(()),
+        },
+    };
+
+    let members = match ctx.data().content.free_company_members(&id).await {
+        Ok(members) => members,
         Err(_) => {
-            ctx.send(|b| b.embed(|e| e.title("invalid world!"))).await?;
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "free-company-not-found"))
+                        .description(tr!(ctx, "free-company-not-found.id"))
+                })
+            })
+            .await?;
+
             return Ok(());
         }
+    };
+    let members: Vec<_> = members
+        .into_iter()
+        .filter(|m| match &rank {
+            Some(rank) => m.rank.eq_ignore_ascii_case(rank),
+            None => true,
+        })
+        .collect();
+
+    if members.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "free-company-no-members"))
+                    .description(match &rank {
+                        Some(rank) => tr!(ctx, "free-company-no-members.rank", rank: rank.as_str()),
+                        None => tr!(ctx, "free-company-no-members.description"),
+                    })
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    let linked = match ctx.guild_id() {
+        Some(guild_id) => {
+            let con = &mut ctx.data().client.get_async_connection().await?;
+
+            let ids: Vec<_> = members.iter().map(|m| m.id.to_string()).collect();
+
+            link::guild_owners(ctx.serenity_context(), con, guild_id, &ids)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!(
+                        "failed to list the characters linked in {}: {}",
+                        guild_id, e
+                    );
+                    HashMap::new()
+                })
+        }
+        None => HashMap::new(),
+    };
+
+    let choices: Vec<_> = members
+        .iter()
+        .map(|m| {
+            let mut choice = Choice::from(m);
+
+            if linked.contains_key(&m.id.to_string()) {
+                choice.description = tr!(
+                    ctx,
+                    "free-company-members.linked",
+                    rank: m.rank.as_str()
+                );
+            }
+
+            choice
+        })
+        .collect();
+
+    if let Some(i) = chooser::choose(ctx, &choices, tr!(ctx, "free-company-members")).await? {
+        let language = language::resolve(ctx, language).await;
+        let response = ctx.data().content.character(members[i].id, language).await;
+
+        character::return_embed("id", response, false, language, ctx).await?;
     }
 
     Ok(())
 }
 
-/// fetch a free company by its Lodestone ID.
-#[poise::command(slash_command)]
-pub async fn id(
+// Discord doesn't let a slash command with subcommands be run on its own, so this can't be
+// a bare `/freecompany`.
+/// show the free company this server belongs to.
+#[poise::command(slash_command, guild_only)]
+pub async fn server(
+    ctx: Context<'_>,
+    #[description = "the language to show game data in"] language: Option<Language>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let bound = free_company::bound(con, guild_id).await?;
+
+    ctx.defer().await?;
+
+    if bound.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "free-company-server-unbound"))
+                    .description(tr!(ctx, "free-company-server-unbound.description"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    let choices: Vec<_> = bound
+        .iter()
+        .map(|(id, name)| Choice {
+            name: name.clone(),
+            description: tr!(ctx, "free-company-lodestone-id", id: id.as_str()),
+            thumbnail: None,
+            url: format!(
+                "https://na.finalfantasyxiv.com/lodestone/freecompany/{}",
+                id
+            ),
+        })
+        .collect();
+
+    if let Some(i) = chooser::choose(ctx, &choices, tr!(ctx, "free-company-pick")).await? {
+        let language = language::resolve(ctx, language).await;
+        let fc = ctx.data().content.free_company(&bound[i].0, language).await;
+
+        return_embed("id", fc, &ctx).await?;
+    }
+
+    Ok(())
+}
+
+/// bind a free company to this server, so its members can be told apart.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn bind(
     ctx: Context<'_>,
     #[description = "the free company's Lodestone ID"] id: String,
 ) -> Result<(), Error> {
-    let api = &ctx.data().api;
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
 
     ctx.defer().await?;
 
-    let response = api
-        .free_company(id.parse::<u64>().unwrap().into())
-        .send()
-        .await;
+    let id = match character::lodestone_id(ctx, &id).await? {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+    let fc = match ctx.data().content.free_company(id, Language::English).await {
+        Ok(r) => r.free_company,
+        Err(_) => None,
+    };
+    let fc = match fc {
+        Some(fc) => fc,
+        None => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "free-company-not-found"))
+                        .description(tr!(ctx, "free-company-not-found.id"))
+                })
+            })
+            .await?;
+
+            return Ok(());
+        }
+    };
+
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let _: () = con
+        .hset(
+            free_company::guild_key(guild_id),
+            fc.id.0.to_string(),
+            &fc.name,
+        )
+        .await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "free-company-bound")).description(tr!(
+                ctx,
+                "free-company-bound.description",
+                name: fc.name.as_str()
+            ))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// autocompletes a free company from the ones bound to this server.
+async fn autocomplete_bound(
+    ctx: Context<'_>,
+    partial: &str,
+) -> Vec<poise::AutocompleteChoice<String>> {
+    let (guild_id, mut con) = match (
+        ctx.guild_id(),
+        ctx.data().client.get_async_connection().await,
+    ) {
+        (Some(guild_id), Ok(con)) => (guild_id, con),
+        _ => return vec![],
+    };
+    let partial = partial.to_lowercase();
+
+    free_company::bound(&mut con, guild_id)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(_, name)| name.to_lowercase().starts_with(&partial))
+        .take(25)
+        .map(|(id, name)| poise::AutocompleteChoice { name, value: id })
+        .collect()
+}
+
+/// unbind a free company from this server.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn unbind(
+    ctx: Context<'_>,
+    #[description = "the bound free company"]
+    #[autocomplete = "autocomplete_bound"]
+    free_company: String,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let bound = free_company::bound(con, guild_id).await?;
+    let found = bound
+        .into_iter()
+        .find(|(id, name)| *id == free_company || name.eq_ignore_ascii_case(&free_company));
+
+    match found {
+        Some((id, name)) => {
+            let _: () = con.hdel(free_company::guild_key(guild_id), &id).await?;
+            free_company::forget_roster(con, &id).await?;
+
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "free-company-unbound-successful"))
+                        .description(tr!(
+                            ctx,
+                            "free-company-unbound-successful.description",
+                            name: name.as_str()
+                        ))
+                })
+            })
+            .await?;
+        }
+        None => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "free-company-not-bound")).description(tr!(
+                        ctx,
+                        "free-company-not-bound.description",
+                        name: free_company.as_str()
+                    ))
+                })
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// post joins, leaves, rank and name changes of this server's free companies in a channel.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn feed(
+    ctx: Context<'_>,
+    #[description = "the channel to post in; leave out to stop posting"]
+    #[channel_types("Text")]
+    channel: Option<serenity::GuildChannel>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let description = match channel {
+        Some(channel) => {
+            let _: () = con
+                .set(free_company::feed_key(guild_id), channel.id.0)
+                .await?;
+            let _: () = con.sadd(free_company::FEED_GUILDS_KEY, guild_id.0).await?;
+
+            tr!(
+                ctx,
+                "free-company-feed-updated.channel",
+                channel: format!("<#{}>", channel.id)
+            )
+        }
+        None => {
+            let _: () = con.del(free_company::feed_key(guild_id)).await?;
+            let _: () = con.srem(free_company::FEED_GUILDS_KEY, guild_id.0).await?;
+
+            for (id, _) in free_company::bound(con, guild_id).await? {
+                free_company::forget_roster(con, &id).await?;
+            }
+
+            tr!(ctx, "free-company-feed-updated.disabled")
+        }
+    };
 
-    return_embed("ID", response, &ctx).await?;
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "free-company-feed-updated"))
+                .description(description)
+        })
+    })
+    .await?;
 
     Ok(())
 }
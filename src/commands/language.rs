@@ -0,0 +1,60 @@
+use crate::{
+    i18n::tr,
+    language::{guild_key, user_key, Language},
+    Context, Error,
+};
+use redis::AsyncCommands;
+
+#[poise::command(slash_command, subcommands("user", "server"), subcommand_required)]
+pub async fn language(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// set the language Kotonya shows game data in for you.
+#[poise::command(slash_command)]
+pub async fn user(
+    ctx: Context<'_>,
+    #[description = "your preferred language"] language: Language,
+) -> Result<(), Error> {
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con.set(user_key(ctx.author().id), language.code()).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "language-updated")).description(tr!(
+                ctx,
+                "language-updated.user",
+                language: tr!(ctx, &format!("language-name.{}", language.code()))
+            ))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// set the language Kotonya shows game data in for this server.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_GUILD")]
+pub async fn server(
+    ctx: Context<'_>,
+    #[description = "the server's preferred language"] language: Language,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con.set(guild_key(guild_id), language.code()).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "language-updated")).description(tr!(
+                ctx,
+                "language-updated.server",
+                language: tr!(ctx, &format!("language-name.{}", language.code()))
+            ))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
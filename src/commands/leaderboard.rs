@@ -0,0 +1,136 @@
+use crate::{
+    commands::search,
+    history::{self, Snapshot},
+    i18n::tr,
+    job, link, Context, Error,
+};
+
+/// what a leaderboard ranks characters by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum Ranking {
+    #[name = "achievement points"]
+    AchievementPoints,
+    #[name = "mounts"]
+    Mounts,
+    #[name = "minions"]
+    Minions,
+    #[name = "total job levels"]
+    TotalLevels,
+    #[name = "a job's level"]
+    Job,
+}
+
+impl Ranking {
+    /// the leaderboard's title in the message catalog.
+    fn message(self) -> &'static str {
+        match self {
+            Ranking::AchievementPoints => "leaderboard-title.achievement-points",
+            Ranking::Mounts => "leaderboard-title.mounts",
+            Ranking::Minions => "leaderboard-title.minions",
+            Ranking::TotalLevels => "leaderboard-title.total-levels",
+            Ranking::Job => "leaderboard-title.job",
+        }
+    }
+
+    /// a character's score, given the `ClassJob` ID of the job being ranked by.
+    fn score(self, snapshot: &Snapshot, job: Option<u64>) -> u64 {
+        match self {
+            Ranking::AchievementPoints => snapshot.achievement_points,
+            Ranking::Mounts => snapshot.mounts as u64,
+            Ranking::Minions => snapshot.minions as u64,
+            Ranking::TotalLevels => snapshot.total_levels(),
+            Ranking::Job => job
+                .and_then(|id| snapshot.jobs.get(&id).copied())
+                .unwrap_or(0),
+        }
+    }
+}
+
+/// rank this server's verified main characters.
+#[poise::command(slash_command, guild_only)]
+pub async fn leaderboard(
+    ctx: Context<'_>,
+    #[description = "what to rank characters by"] ranking: Ranking,
+    #[description = "the job to rank by, when ranking by a job's level"]
+    #[autocomplete = "crate::job::autocomplete"]
+    job: Option<String>,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+
+    let job = match (ranking, job.as_deref().map(job::by_name)) {
+        (Ranking::Job, Some(Some(job))) => Some(job),
+        (Ranking::Job, _) => {
+            ctx.send(|b| {
+                b.embed(|e| {
+                    e.title(tr!(ctx, "leaderboard-unknown-job"))
+                        .description(tr!(ctx, "leaderboard-unknown-job.description"))
+                })
+            })
+            .await?;
+
+            return Ok(());
+        }
+        _ => None,
+    };
+    let title = match job {
+        Some((_, name)) => tr!(ctx, "leaderboard-title.job", job: name),
+        None => tr!(ctx, ranking.message()),
+    };
+
+    ctx.defer().await?;
+
+    let http = &ctx.serenity_context().http;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+    let linked = link::linked_users(con).await?;
+    let mut entries = vec![];
+
+    for member in link::guild_members(http, guild_id).await? {
+        let user = member.user.id;
+
+        // most members haven't linked anything, so they're skipped without asking Redis.
+        if !linked.contains(&user.0) || link::is_hidden(con, user).await? {
+            continue;
+        }
+
+        // anyone can link any character, so only verified mains are ranked.
+        let id = match link::resolve(con, user, None).await? {
+            Some(id) if link::is_verified(con, user, &id).await? => id,
+            _ => continue,
+        };
+
+        // rankings come from the tracker's snapshots rather than fresh fetches, so a
+        // character only shows up once it's been snapshotted.
+        if let Some(snapshot) = history::latest(con, user, &id).await? {
+            let name = link::characters(con, user)
+                .await?
+                .into_iter()
+                .find(|(c, _)| *c == id)
+                .map_or(id, |(_, name)| name);
+
+            entries.push((name, user, ranking.score(&snapshot, job.map(|(id, _)| id))));
+        }
+    }
+
+    if entries.is_empty() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "leaderboard-empty"))
+                    .description(tr!(ctx, "leaderboard-empty.description"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    entries.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    let lines: Vec<_> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, (name, user, score))| {
+            format!("**{}.** {} — {} (<@{}>)", i + 1, name, score, user)
+        })
+        .collect();
+
+    search::paginate_list(ctx, title, &lines).await
+}
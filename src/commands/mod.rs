@@ -1,4 +1,10 @@
+pub mod announcements;
 pub mod character;
 pub mod free_company;
+pub mod language;
+pub mod leaderboard;
+pub mod nickname;
 pub mod ping;
+pub mod privacy;
+pub mod roles;
 pub mod search;
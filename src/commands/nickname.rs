@@ -0,0 +1,121 @@
+use crate::{
+    i18n::tr,
+    nickname::{self, NicknameFormat, GUILDS_KEY},
+    Context, Error,
+};
+use redis::AsyncCommands;
+
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_NICKNAMES",
+    subcommands("enable", "disable", "sync"),
+    subcommand_required
+)]
+pub async fn nicknames(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// rename members in this server after their linked main character.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_NICKNAMES")]
+pub async fn enable(
+    ctx: Context<'_>,
+    #[description = "how nicknames are written"] format: NicknameFormat,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con
+        .set(nickname::nickname_key(guild_id), format.key())
+        .await?;
+    let _: () = con.sadd(GUILDS_KEY, guild_id.0).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "nicknames-updated"))
+                .description(tr!(ctx, "nicknames-updated.enabled"))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// stop renaming members in this server. nicknames Kotonya already set are kept.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_NICKNAMES")]
+pub async fn disable(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con.del(nickname::nickname_key(guild_id)).await?;
+    let _: () = con.srem(GUILDS_KEY, guild_id.0).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "nicknames-updated"))
+                .description(tr!(ctx, "nicknames-updated.disabled"))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// rename every linked member in this server now, and list anyone Kotonya couldn't rename.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_NICKNAMES")]
+pub async fn sync(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    if nickname::format(con, guild_id).await?.is_none() {
+        ctx.send(|b| {
+            b.embed(|e| {
+                e.title(tr!(ctx, "nicknames-disabled"))
+                    .description(tr!(ctx, "nicknames-disabled.description"))
+            })
+        })
+        .await?;
+
+        return Ok(());
+    }
+
+    ctx.defer().await?;
+
+    let forbidden = nickname::sync_guild(
+        &ctx.serenity_context().http,
+        con,
+        &ctx.data().content,
+        guild_id,
+    )
+    .await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "nicknames-synced"));
+
+            if forbidden.is_empty() {
+                e.description(tr!(ctx, "nicknames-synced.description"))
+            } else {
+                e.description(tr!(
+                    ctx,
+                    "nicknames-synced.forbidden",
+                    count: forbidden.len()
+                ))
+                .field(
+                    tr!(ctx, "nicknames-synced.members"),
+                    // embed fields hold 1024 characters, which fits about 40 mentions.
+                    forbidden
+                        .iter()
+                        .take(40)
+                        .map(|u| format!("<@{}>", u))
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                    false,
+                )
+            }
+        })
+    })
+    .await?;
+
+    Ok(())
+}
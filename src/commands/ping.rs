@@ -1,9 +1,9 @@
-use crate::{Context, Error};
+use crate::{i18n::tr, Context, Error};
 
 /// a ping command.
 #[poise::command(slash_command)]
 pub async fn ping(ctx: Context<'_>) -> Result<(), Error> {
-    ctx.send(|b| b.embed(|e| e.title("pong!"))).await?;
+    ctx.send(|b| b.embed(|e| e.title(tr!(ctx, "pong")))).await?;
 
     Ok(())
 }
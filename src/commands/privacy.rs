@@ -0,0 +1,126 @@
+use crate::{history, i18n::tr, language, link, Context, Error};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands, RedisError};
+
+/// every Redis key holding data about a user.
+async fn user_keys(con: &mut Connection, id: serenity::UserId) -> Result<Vec<String>, RedisError> {
+    let mut keys = link::keys(id);
+    keys.push(language::user_key(id));
+
+    for (character, _) in link::characters(con, id).await? {
+        keys.push(link::verification_key(id, &character));
+        keys.push(history::history_key(id, character));
+    }
+
+    Ok(keys)
+}
+
+/// delete everything Kotonya stores about you.
+#[poise::command(slash_command, rename = "forget-me")]
+pub async fn forget_me(ctx: Context<'_>) -> Result<(), Error> {
+    let ctx_id = ctx.id();
+    let confirm = format!("{}confirm", ctx_id);
+    let cancel = format!("{}cancel", ctx_id);
+
+    ctx.send(|b| {
+        b.ephemeral(true)
+            .embed(|e| {
+                e.title(tr!(ctx, "forget-me-confirm"))
+                    .description(tr!(ctx, "forget-me-confirm.description"))
+            })
+            .components(|c| {
+                c.create_action_row(|r| {
+                    r.create_button(|b| {
+                        b.custom_id(&confirm)
+                            .style(serenity::ButtonStyle::Danger)
+                            .label(tr!(ctx, "forget-me-confirm.confirm"))
+                    })
+                    .create_button(|b| {
+                        b.custom_id(&cancel)
+                            .style(serenity::ButtonStyle::Secondary)
+                            .label(tr!(ctx, "forget-me-confirm.cancel"))
+                    })
+                })
+            })
+    })
+    .await?;
+
+    let press = match serenity::CollectComponentInteraction::new(ctx)
+        .author_id(ctx.author().id)
+        .filter(move |press| press.data.custom_id.starts_with(&ctx_id.to_string()))
+        .timeout(std::time::Duration::from_secs(60))
+        .await
+    {
+        Some(press) => press,
+        None => return Ok(()),
+    };
+
+    let message = if press.data.custom_id == confirm {
+        let con = &mut ctx.data().client.get_async_connection().await?;
+        let user = ctx.author().id;
+        let keys = user_keys(con, user).await?;
+
+        for (id, _) in link::characters(con, user).await? {
+            let _: () = con.srem(link::owners_key(id), user.0).await?;
+        }
+        let _: () = con.srem(link::LINKED_USERS_KEY, user.0).await?;
+        let _: () = con.del(keys).await?;
+
+        "forget-me-done"
+    } else {
+        "forget-me-cancelled"
+    };
+
+    press
+        .create_interaction_response(ctx, |b| {
+            b.kind(serenity::InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|b| {
+                    b.embed(|e| {
+                        e.title(tr!(ctx, message))
+                            .description(tr!(ctx, &format!("{}.description", message)))
+                    })
+                    .components(|c| c)
+                })
+        })
+        .await?;
+
+    if press.data.custom_id == confirm {
+        link::changed(ctx).await;
+    }
+
+    Ok(())
+}
+
+/// hide your linked characters from other members, or show them again.
+#[poise::command(slash_command)]
+pub async fn privacy(
+    ctx: Context<'_>,
+    #[description = "whether other members can look up your characters"] hidden: bool,
+) -> Result<(), Error> {
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = if hidden {
+        con.set(link::hidden_key(ctx.author().id), 1).await?
+    } else {
+        con.del(link::hidden_key(ctx.author().id)).await?
+    };
+
+    ctx.send(|b| {
+        b.ephemeral(true).embed(|e| {
+            e.title(tr!(ctx, "privacy-updated")).description(tr!(
+                ctx,
+                if hidden {
+                    "privacy-updated.hidden"
+                } else {
+                    "privacy-updated.visible"
+                }
+            ))
+        })
+    })
+    .await?;
+
+    // hiding also stops Kotonya naming the user after their character.
+    link::changed(ctx).await;
+
+    Ok(())
+}
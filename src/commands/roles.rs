@@ -0,0 +1,241 @@
+use crate::{
+    i18n::tr,
+    roles::{self, CombatRole, GrandCompany, GUILDS_KEY},
+    world, Context, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::AsyncCommands;
+use std::collections::HashMap;
+
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    subcommands(
+        "verified",
+        "world",
+        "grand_company",
+        "combat_role",
+        "free_company",
+        "remove",
+        "list",
+        "sync"
+    ),
+    subcommand_required
+)]
+pub async fn roles(_: Context<'_>) -> Result<(), Error> {
+    Ok(())
+}
+
+/// map a role to a mapping in this server, and tell the invoker.
+async fn set_mapping(ctx: Context<'_>, mapping: String, role: serenity::Role) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let _: () = con
+        .hset(roles::roles_key(guild_id), &mapping, role.id.0)
+        .await?;
+    let _: () = con.sadd(GUILDS_KEY, guild_id.0).await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "roles-updated")).description(tr!(
+                ctx,
+                "roles-updated.set",
+                mapping: mapping,
+                role: format!("<@&{}>", role.id)
+            ))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// give members who have verified their main character a role.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn verified(
+    ctx: Context<'_>,
+    #[description = "the role to give"] role: serenity::Role,
+) -> Result<(), Error> {
+    set_mapping(ctx, "verified".to_string(), role).await
+}
+
+/// give members whose main character is on a world, data center or region a role.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn world(
+    ctx: Context<'_>,
+    #[description = "a world, data center or region"]
+    #[autocomplete = "crate::world::autocomplete"]
+    world: String,
+    #[description = "the role to give"] role: serenity::Role,
+) -> Result<(), Error> {
+    if world::scope(&world).is_none() {
+        ctx.send(|b| b.embed(|e| e.title(tr!(ctx, "invalid-world"))))
+            .await?;
+        return Ok(());
+    }
+
+    set_mapping(ctx, format!("world:{}", world.to_lowercase()), role).await
+}
+
+/// give members whose main character is enlisted in a Grand Company a role.
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    rename = "grand-company"
+)]
+pub async fn grand_company(
+    ctx: Context<'_>,
+    #[description = "the Grand Company"] company: GrandCompany,
+    #[description = "the role to give"] role: serenity::Role,
+) -> Result<(), Error> {
+    set_mapping(ctx, format!("gc:{}", company.key()), role).await
+}
+
+/// give members whose main character's active job is a tank, healer or DPS a role.
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    rename = "combat-role"
+)]
+pub async fn combat_role(
+    ctx: Context<'_>,
+    #[description = "the part the job plays in a party"] kind: CombatRole,
+    #[description = "the role to give"] role: serenity::Role,
+) -> Result<(), Error> {
+    set_mapping(ctx, format!("type:{}", kind.key()), role).await
+}
+
+/// give members whose main character is in one of this server's free companies a role.
+#[poise::command(
+    slash_command,
+    guild_only,
+    required_permissions = "MANAGE_ROLES",
+    rename = "free-company"
+)]
+pub async fn free_company(
+    ctx: Context<'_>,
+    #[description = "the role to give"] role: serenity::Role,
+) -> Result<(), Error> {
+    set_mapping(ctx, "free-company".to_string(), role).await
+}
+
+/// autocompletes a mapping from the ones configured in this server.
+async fn autocomplete_mapping(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let (guild_id, mut con) = match (
+        ctx.guild_id(),
+        ctx.data().client.get_async_connection().await,
+    ) {
+        (Some(guild_id), Ok(con)) => (guild_id, con),
+        _ => return vec![],
+    };
+    let partial = partial.to_lowercase();
+
+    let mapping: HashMap<String, u64> = con
+        .hgetall(roles::roles_key(guild_id))
+        .await
+        .unwrap_or_default();
+    let mut keys: Vec<_> = mapping
+        .into_keys()
+        .filter(|k| k.contains(&partial))
+        .collect();
+    keys.sort();
+    keys.truncate(25);
+
+    keys
+}
+
+/// stop giving a role for a mapping. members who already have the role keep it, since
+/// Kotonya no longer manages it.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn remove(
+    ctx: Context<'_>,
+    #[description = "the mapping to remove"]
+    #[autocomplete = "autocomplete_mapping"]
+    mapping: String,
+) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let removed: bool = con.hdel(roles::roles_key(guild_id), &mapping).await?;
+    let remaining: bool = con.exists(roles::roles_key(guild_id)).await?;
+    if !remaining {
+        let _: () = con.srem(GUILDS_KEY, guild_id.0).await?;
+    }
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            if removed {
+                e.title(tr!(ctx, "roles-updated")).description(tr!(
+                    ctx,
+                    "roles-updated.removed",
+                    mapping: mapping.as_str()
+                ))
+            } else {
+                e.title(tr!(ctx, "roles-unmapped", mapping: mapping.as_str()))
+            }
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// list the roles Kotonya gives in this server.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn list(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    let mapping: HashMap<String, u64> = con.hgetall(roles::roles_key(guild_id)).await?;
+    let mut lines: Vec<_> = mapping
+        .into_iter()
+        .map(|(mapping, role)| format!("`{}` → <@&{}>", mapping, role))
+        .collect();
+    lines.sort();
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "roles-mapping"));
+
+            if lines.is_empty() {
+                e.description(tr!(ctx, "roles-mapping.empty"))
+            } else {
+                e.description(lines.join("\n"))
+            }
+        })
+    })
+    .await?;
+
+    Ok(())
+}
+
+/// refresh every linked member's roles in this server now.
+#[poise::command(slash_command, guild_only, required_permissions = "MANAGE_ROLES")]
+pub async fn sync(ctx: Context<'_>) -> Result<(), Error> {
+    let guild_id = ctx.guild_id().ok_or("not in a guild")?;
+    let con = &mut ctx.data().client.get_async_connection().await?;
+
+    ctx.defer().await?;
+
+    let synced = roles::sync_guild(
+        &ctx.serenity_context().http,
+        con,
+        &ctx.data().content,
+        guild_id,
+    )
+    .await?;
+
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(tr!(ctx, "roles-synced"))
+                .description(tr!(ctx, "roles-synced.description", count: synced))
+        })
+    })
+    .await?;
+
+    Ok(())
+}
@@ -1,927 +1,971 @@
-// TODO: improve on the search results - they're very primitive right now,
-// just a name and a XIVAPI ID. it'd be better if i can return the XIVAPI
-// data immediately instead of having users refetching with the ID.
-use crate::{Context, Error};
-use poise::serenity_prelude as serenity;
-use xivapi::{
-    models::search::{SearchModel, SearchResult},
-    prelude::Builder,
+use crate::{
+    content::{field, flag},
+    i18n::tr,
+    language::{self, Language},
+    Context, Data, Error,
 };
+use poise::serenity_prelude as serenity;
+use redis::{AsyncCommands, RedisError};
+use serde_json::Value;
+use std::sync::Arc;
+use tracing::warn;
+use xivapi::models::search::SearchModel;
+
+/// a searchable XIVAPI index.
+struct SearchIndex {
+    /// the name of the index's subcommand.
+    command: &'static str,
+    /// the XIVAPI sheet backing the index, which is also the index's name.
+    sheet: &'static str,
+    /// the message naming the index's content, with a `.plural` attribute.
+    kind: &'static str,
+}
 
-async fn paginate<U, E>(
-    ctx: poise::Context<'_, U, E>,
-    results: &SearchResult,
-) -> Result<(), serenity::Error> {
-    let ctx_id = ctx.id();
-    let prev_button_id = format!("{}prev", ctx.id());
-    let next_button_id = format!("{}next", ctx.id());
+/// generates everything an index needs from its entry: a `search` subcommand for it, the
+/// `search` command itself with every subcommand, and the lookups between indexes, their
+/// subcommands and their search results.
+///
+/// an entry is the subcommand's doc comment, then
+/// `command: SearchModel variant, "sheet", "name parameter description", [detail columns]`,
+/// where the detail columns are the `(label, column)` pairs shown as fields in a result's
+/// detail view and `label` is the field name's message ID. the content is named by the
+/// `search-kind-<command>` message.
+macro_rules! search_commands {
+    ($(
+        #[doc = $doc:tt]
+        $command:ident: $index:ident, $sheet:tt, $description:tt, [$($column:expr),* $(,)?];
+    )*) => {
+        $(
+            #[doc = $doc]
+            #[poise::command(slash_command)]
+            pub async fn $command(
+                ctx: Context<'_>,
+                #[description = $description]
+                #[autocomplete = "autocomplete_name"]
+                name: String,
+                #[description = "the language to show results in"] language: Option<Language>,
+            ) -> Result<(), Error> {
+                let index = SearchIndex {
+                    command: stringify!($command),
+                    sheet: $sheet,
+                    kind: concat!("search-kind-", stringify!($command)),
+                };
+
+                run_search(ctx, index, &name, language).await
+            }
+        )*
 
-    let mut current_page = 0;
-    let pages_vec = results.results.chunks(5).collect::<Vec<_>>();
-    let pages = pages_vec.as_slice();
+        /// `search`, with [`all`] and a subcommand for every index.
+        pub fn search() -> poise::Command<Data, Error> {
+            let mut search = search_group();
+            search.subcommands = vec![all(), $($command()),*];
+            search.subcommand_required = true;
 
-    ctx.send(|b| {
-        for result in pages[current_page] {
+            search
+        }
+
+        /// the name of every generated subcommand.
+        const SEARCH_COMMANDS: &[&str] = &[$(stringify!($command)),*];
+
+        /// the index a search result came from.
+        fn result_index(result: &SearchModel) -> SearchIndex {
             match result {
-                SearchModel::Achievement(a)
-                | SearchModel::Action(a)
-                | SearchModel::Emote(a)
-                | SearchModel::Enemy(a)
-                | SearchModel::Fate(a)
-                | SearchModel::InstanceContent(a)
-                | SearchModel::Item(a)
-                | SearchModel::Leve(a)
-                | SearchModel::Minion(a)
-                | SearchModel::Mount(a)
-                | SearchModel::Npc(a)
-                | SearchModel::PlaceName(a)
-                | SearchModel::Quest(a)
-                | SearchModel::Recipe(a)
-                | SearchModel::Status(a)
-                | SearchModel::Title(a)
-                | SearchModel::Weather(a) => {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
+                $(
+                    SearchModel::$index(_) => SearchIndex {
+                        command: stringify!($command),
+                        sheet: $sheet,
+                        kind: concat!("search-kind-", stringify!($command)),
+                    },
+                )*
             }
         }
 
-        b.components(|b| {
-            b.create_action_row(|b| {
-                b.create_button(|b| b.custom_id(&prev_button_id).emoji('◀'))
-                    .create_button(|b| b.custom_id(&next_button_id).emoji('▶'))
-            })
-        })
-    })
-    .await?;
+        /// the index searched by the subcommand with the given name.
+        fn search_index(command: &str) -> Option<SearchIndex> {
+            match command {
+                $(
+                    stringify!($command) => Some(SearchIndex {
+                        command: stringify!($command),
+                        sheet: $sheet,
+                        kind: concat!("search-kind-", stringify!($command)),
+                    }),
+                )*
+                _ => None,
+            }
+        }
 
-    while let Some(press) = serenity::CollectComponentInteraction::new(ctx)
-        .filter(move |press| press.data.custom_id.starts_with(&ctx_id.to_string()))
-        .timeout(std::time::Duration::from_secs(10))
-        .await
-    {
-        if press.data.custom_id == next_button_id {
-            current_page += 1;
-            if current_page >= pages.len() {
-                current_page = 0;
+        /// the name, XIVAPI ID and icon path of a search result.
+        fn summary(result: &SearchModel) -> (&str, String, String) {
+            match result {
+                $(SearchModel::$index(a))|* => {
+                    (a.name.as_str(), a.id.to_string(), a.icon.to_string())
+                }
             }
-        } else if press.data.custom_id == prev_button_id {
-            current_page = current_page.checked_sub(1).unwrap_or(pages.len() - 1);
-        } else {
-            continue;
         }
 
-        press
-            .create_interaction_response(ctx, |b| {
-                b.kind(serenity::InteractionResponseType::UpdateMessage)
-                    .interaction_response_data(|b| {
-                        for result in pages[current_page] {
-                            match result {
-                                SearchModel::Achievement(a)
-                                | SearchModel::Action(a)
-                                | SearchModel::Emote(a)
-                                | SearchModel::Enemy(a)
-                                | SearchModel::Fate(a)
-                                | SearchModel::InstanceContent(a)
-                                | SearchModel::Item(a)
-                                | SearchModel::Leve(a)
-                                | SearchModel::Minion(a)
-                                | SearchModel::Mount(a)
-                                | SearchModel::Npc(a)
-                                | SearchModel::PlaceName(a)
-                                | SearchModel::Quest(a)
-                                | SearchModel::Recipe(a)
-                                | SearchModel::Status(a)
-                                | SearchModel::Title(a)
-                                | SearchModel::Weather(a) => {
-                                    b.embed(|f| {
-                                        f.title(&a.name)
-                                            .description(format!("ID: {}", &a.id))
-                                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                                    });
-                                }
-                            }
-                        }
+        /// the `(label, column)` pairs shown as fields in a search result's detail view.
+        fn detail_columns(result: &SearchModel) -> &'static [(&'static str, &'static str)] {
+            match result {
+                $(SearchModel::$index(_) => &[$($column),*],)*
+            }
+        }
+    };
+}
 
-                        b
-                    })
-            })
-            .await?;
+/// the localized name of the content in an index, e.g. "item" or "items".
+fn kind(ctx: Context<'_>, search: &SearchIndex, plural: bool) -> String {
+    if plural {
+        tr!(ctx, &format!("{}.plural", search.kind))
+    } else {
+        tr!(ctx, search.kind)
     }
+}
 
-    Ok(())
+/// fills an embed with the summary of a single search result.
+fn summary_embed<'a>(
+    ctx: Context<'_>,
+    e: &'a mut serenity::CreateEmbed,
+    result: &SearchModel,
+) -> &'a mut serenity::CreateEmbed {
+    let (name, id, icon) = summary(result);
+
+    e.title(name)
+        .description(format!("ID: {}", id))
+        .thumbnail(format!("https://xivapi.com/{}", icon))
+        .footer(|f| f.text(kind(ctx, &result_index(result), false)))
 }
 
-#[poise::command(
-    slash_command,
-    subcommands(
-        "achievement",
-        "action",
-        "emote",
-        "enemy",
-        "fate",
-        "instance_content",
-        "item",
-        "leve",
-        "minion",
-        "mount",
-        "npc",
-        "place",
-        "quest",
-        "recipe",
-        "status",
-        "title",
-        "weather"
-    ),
-    subcommand_required
-)]
-pub async fn search(_: Context<'_>) -> Result<(), Error> {
-    Ok(())
+/// the name and XIVAPI ID of a search result.
+fn name_and_id(result: &SearchModel) -> (&str, String) {
+    let (name, id, _) = summary(result);
+
+    (name, id)
 }
 
-/// search for an in-game achievement.
-#[poise::command(slash_command)]
-pub async fn achievement(
-    ctx: Context<'_>,
-    #[description = "the achievement's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// formats a column stored in tenths of a second (e.g. `Cast100ms`) as seconds.
+fn seconds(row: &Value, column: &str) -> Option<String> {
+    let tenths = row.get(column)?.as_f64()?;
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Achievement)
-        .send()
-        .await?;
+    Some(format!("{:.2}s", tenths / 10.0))
+}
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("achievement not found!").description(
-                    "Kotonya couldn't find any achievements with the specified name, nya!",
-                )
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Achievement(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+/// a tooltip without the `<...>` markup tags the game formats it with.
+fn plain_text(text: &str) -> String {
+    let mut plain = String::with_capacity(text.len());
+    let mut in_tag = false;
+
+    for c in text.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => plain.push(c),
+            _ => {}
+        }
     }
 
-    Ok(())
+    plain
 }
 
-/// search for an in-game action.
-#[poise::command(slash_command)]
-pub async fn action(
-    ctx: Context<'_>,
-    #[description = "the action's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
-
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Action)
-        .send()
-        .await?;
+/// an action's base potency, e.g. "with a potency of 220".
+///
+/// the game data has no potency column, only the tooltip, so it's read from the English
+/// description XIVAPI includes whatever language the row was requested in, to show it in
+/// every language.
+fn potency(row: &Value) -> Option<String> {
+    let description = plain_text(row.get("Description_en")?.as_str()?);
+    let (_, rest) = description.split_once("potency of ")?;
+    let potency = rest
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .collect::<String>();
+
+    (!potency.is_empty()).then_some(potency)
+}
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("action not found!")
-                    .description("Kotonya couldn't find any actions with the specified name, nya!")
+/// the stats of an item, with their NQ and HQ values.
+fn item_stats(row: &Value) -> Option<String> {
+    let stats = row.get("Stats")?.as_object()?;
+    let lines = stats
+        .iter()
+        .filter_map(|(name, stat)| {
+            let nq = field(stat, "NQ")?;
+
+            Some(match field(stat, "HQ") {
+                Some(hq) if hq != nq => format!("{}: {} (HQ {})", name, nq, hq),
+                _ => format!("{}: {}", name, nq),
             })
         })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Action(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
-    }
+        .collect::<Vec<_>>();
 
-    Ok(())
+    (!lines.is_empty()).then(|| lines.join("\n"))
 }
 
-/// search for an in-game emote.
-#[poise::command(slash_command)]
-pub async fn emote(
-    ctx: Context<'_>,
-    #[description = "the emote's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// where an item can be obtained from, based on the content linking to it.
+fn item_sources(ctx: Context<'_>, row: &Value) -> Option<String> {
+    const SOURCES: &[(&str, &str)] = &[
+        ("Recipe", "search-source-crafting"),
+        ("GilShopItem", "search-source-gil-shops"),
+        ("SpecialShop", "search-source-exchange-shops"),
+        ("GCScripShopItem", "search-source-grand-company"),
+        ("GatheringItem", "search-source-gathering"),
+        ("RetainerTaskNormal", "search-source-retainer-ventures"),
+        ("QuestReward", "search-source-quest-rewards"),
+    ];
+
+    let links = row.get("GameContentLinks")?;
+    let sources = SOURCES
+        .iter()
+        .filter(|(sheet, _)| links.get(sheet).is_some())
+        .map(|(_, source)| tr!(ctx, source))
+        .collect::<Vec<_>>();
+
+    (!sources.is_empty()).then(|| sources.join(", "))
+}
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Emote)
-        .send()
-        .await?;
+/// the ingredients of a recipe, with their amounts.
+fn recipe_ingredients(row: &Value) -> Option<String> {
+    let lines = (0..10)
+        .filter_map(|i| {
+            let name = field(row, &format!("ItemIngredient{}.Name", i))?;
+            let amount = field(row, &format!("AmountIngredient{}", i))?;
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("emote not found!")
-                    .description("Kotonya couldn't find any emotes with the specified name, nya!")
-            })
+            Some(format!("{}x {}", amount, name))
         })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Emote(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
-    }
+        .collect::<Vec<_>>();
 
-    Ok(())
+    (!lines.is_empty()).then(|| lines.join("\n"))
 }
 
-/// search for an in-game enemy.
-#[poise::command(slash_command)]
-pub async fn enemy(
+/// fills an embed with the full detail view of a single search result, using its XIVAPI content row.
+fn detail_embed<'a>(
     ctx: Context<'_>,
-    #[description = "the action's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+    e: &'a mut serenity::CreateEmbed,
+    result: &SearchModel,
+    row: &Value,
+) -> &'a mut serenity::CreateEmbed {
+    summary_embed(ctx, e, result);
+
+    let description = field(row, "Description");
+    if let Some(description) = &description {
+        e.description(format!(
+            "ID: {}\n```{}```",
+            name_and_id(result).1,
+            description
+        ));
+    }
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Enemy)
-        .send()
-        .await?;
+    for (label, column) in detail_columns(result) {
+        let value = field(row, column).or_else(|| {
+            flag(row, column).map(|b| tr!(ctx, if b { "search-yes" } else { "search-no" }))
+        });
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("enemy not found!")
-                    .description("Kotonya couldn't find any enemies with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Enemy(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
+        if let Some(value) = value {
+            e.field(tr!(ctx, label), value, true);
+        }
+    }
+
+    match result {
+        SearchModel::Action(_) => {
+            if let Some(potency) = potency(row) {
+                e.field(tr!(ctx, "search-field-potency"), potency, true);
             }
-            b
-        })
-        .await?;
+            if let Some(cast) = seconds(row, "Cast100ms") {
+                e.field(tr!(ctx, "search-field-cast"), cast, true);
+            }
+            if let Some(recast) = seconds(row, "Recast100ms") {
+                e.field(tr!(ctx, "search-field-recast"), recast, true);
+            }
+        }
+        SearchModel::Item(_) => {
+            if let Some(stats) = item_stats(row) {
+                e.field(tr!(ctx, "search-field-stats"), stats, false);
+            }
+            if let Some(sources) = item_sources(ctx, row) {
+                e.field(tr!(ctx, "search-field-sources"), sources, false);
+            }
+        }
+        SearchModel::Recipe(_) => {
+            if let Some(ingredients) = recipe_ingredients(row) {
+                e.field(tr!(ctx, "search-field-ingredients"), ingredients, false);
+            }
+        }
+        _ => {}
     }
 
-    Ok(())
+    e
 }
 
-/// search for an in-game fate.
-#[poise::command(slash_command)]
-pub async fn fate(
-    ctx: Context<'_>,
-    #[description = "the FATE's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// fetches the XIVAPI content row behind a search result, for use with [`detail_embed`].
+async fn fetch_detail(ctx: Context<'_>, result: &SearchModel, language: Language) -> Option<Value> {
+    let sheet = result_index(result).sheet;
 
-    let search_result = &ctx
+    match ctx
         .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Fate)
-        .send()
-        .await?;
-
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("fate not found!")
-                    .description("Kotonya couldn't find any FATEs with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Fate(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        .content
+        .get(sheet, &name_and_id(result).1, language)
+        .await
+    {
+        Ok(row) => Some(row),
+        Err(e) => {
+            warn!("failed to fetch {} content: {}", sheet, e);
+            None
+        }
     }
+}
 
-    Ok(())
+/// the ◀/▶ navigation shared by every paginated message, along with the collector loop
+/// behind it.
+///
+/// custom IDs are prefixed with the invoking command's ID so presses on other messages are
+/// ignored; [`Pager::id`] makes IDs for a message's own components.
+pub struct Pager {
+    prefix: String,
+    prev: String,
+    next: String,
+    /// only presses by this user are collected, if set.
+    author: Option<serenity::UserId>,
+    len: usize,
+    per_page: usize,
+    page: usize,
 }
 
-/// search for an in-game instanced content.
-#[poise::command(slash_command)]
-pub async fn instance_content(
-    ctx: Context<'_>,
-    #[description = "the instanced content's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// a press on one of a paginated message's components.
+pub enum PagerPress {
+    /// a navigation button, after which the message needs redrawing for the new page.
+    Turned(Arc<serenity::MessageComponentInteraction>),
+    /// one of the message's own components.
+    Component(Arc<serenity::MessageComponentInteraction>),
+}
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::InstanceContent)
-        .send()
-        .await?;
+impl Pager {
+    /// pages through `len` items, `per_page` at a time.
+    pub fn new(ctx: Context<'_>, len: usize, per_page: usize) -> Self {
+        let prefix = ctx.id().to_string();
+
+        Self {
+            prev: format!("{}prev", prefix),
+            next: format!("{}next", prefix),
+            prefix,
+            author: None,
+            len,
+            per_page,
+            page: 0,
+        }
+    }
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("instanced content not found!").description(
-                    "Kotonya couldn't find any instanced content with the specified name, nya!",
-                )
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::InstanceContent(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+    /// ignore presses by anyone but the invoking user.
+    pub fn author_only(mut self, ctx: Context<'_>) -> Self {
+        self.author = Some(ctx.author().id);
+        self
     }
 
-    Ok(())
-}
+    /// the custom ID of one of the message's own components.
+    pub fn id(&self, name: &str) -> String {
+        format!("{}{}", self.prefix, name)
+    }
 
-/// search for an in-game item.
-#[poise::command(slash_command)]
-pub async fn item(
-    ctx: Context<'_>,
-    #[description = "the item's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+    /// the current page, counting from 0.
+    pub fn page(&self) -> usize {
+        self.page
+    }
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Item)
-        .send()
-        .await?;
+    pub fn page_count(&self) -> usize {
+        ((self.len + self.per_page - 1) / self.per_page).max(1)
+    }
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("item not found!")
-                    .description("Kotonya couldn't find any items with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Item(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+    /// the index of the current page's first item.
+    pub fn offset(&self) -> usize {
+        self.page * self.per_page
     }
 
-    Ok(())
-}
+    /// the items on the current page.
+    pub fn items<'a, T>(&self, items: &'a [T]) -> &'a [T] {
+        items
+            .chunks(self.per_page)
+            .nth(self.page)
+            .unwrap_or_default()
+    }
 
-/// search for an in-game leve.
-#[poise::command(slash_command)]
-pub async fn leve(
-    ctx: Context<'_>,
-    #[description = "the leve's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+    /// page through a different number of items, starting over from the first page.
+    pub fn reset(&mut self, len: usize) {
+        self.len = len;
+        self.page = 0;
+    }
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Leve)
-        .send()
-        .await?;
+    /// adds the navigation buttons, if there's more than one page.
+    pub fn buttons<'a>(
+        &self,
+        c: &'a mut serenity::CreateComponents,
+    ) -> &'a mut serenity::CreateComponents {
+        if self.page_count() > 1 {
+            c.create_action_row(|r| {
+                r.create_button(|b| b.custom_id(&self.prev).emoji('◀'))
+                    .create_button(|b| b.custom_id(&self.next).emoji('▶'))
+            });
+        }
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("leve not found!")
-                    .description("Kotonya couldn't find any leve with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Leve(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        c
     }
 
-    Ok(())
-}
+    /// waits for the next press on the message's components, turning the page if it's a
+    /// navigation button. returns `None` once nobody's pressed anything for a minute.
+    pub async fn next_press(&mut self, ctx: Context<'_>) -> Option<PagerPress> {
+        let prefix = self.prefix.clone();
+        let mut collector = serenity::CollectComponentInteraction::new(ctx)
+            .filter(move |press| press.data.custom_id.starts_with(&prefix))
+            .timeout(std::time::Duration::from_secs(60));
+        if let Some(author) = self.author {
+            collector = collector.author_id(author);
+        }
 
-/// search for an in-game minion.
-#[poise::command(slash_command)]
-pub async fn minion(
-    ctx: Context<'_>,
-    #[description = "the minion's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+        let press = collector.await?;
+        let page_count = self.page_count();
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Minion)
-        .send()
-        .await?;
+        if press.data.custom_id == self.next {
+            self.page = (self.page + 1) % page_count;
+        } else if press.data.custom_id == self.prev {
+            self.page = self.page.checked_sub(1).unwrap_or(page_count - 1);
+        } else {
+            return Some(PagerPress::Component(press));
+        }
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("minion not found!")
-                    .description("Kotonya couldn't find any minions with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Minion(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        Some(PagerPress::Turned(press))
     }
+}
 
-    Ok(())
+/// the custom IDs of a paginated search's own components.
+struct PageIds {
+    select: String,
+    filter: String,
+    back: String,
 }
 
-/// search for an in-game mount.
-#[poise::command(slash_command)]
-pub async fn mount(
+/// adds the "pick a result" select menu for a page of results, a content type filter if
+/// the results span more than one type, and the navigation buttons.
+fn page_components<'a>(
     ctx: Context<'_>,
-    #[description = "the mount's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
-
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Mount)
-        .send()
-        .await?;
-
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("mount not found!")
-                    .description("Kotonya couldn't find any mounts with the specified name, nya!")
+    c: &'a mut serenity::CreateComponents,
+    ids: &PageIds,
+    pager: &Pager,
+    page: &[&SearchModel],
+    kinds: &[&'static str],
+) -> &'a mut serenity::CreateComponents {
+    c.create_action_row(|r| {
+        r.create_select_menu(|m| {
+            m.custom_id(&ids.select)
+                .placeholder(tr!(ctx, "search-pick"))
+                .options(|o| {
+                    for (i, result) in page.iter().enumerate() {
+                        let (name, id) = name_and_id(result);
+                        o.create_option(|o| {
+                            o.label(name).value(pager.offset() + i).description(format!(
+                                "{} | ID: {}",
+                                kind(ctx, &result_index(result), false),
+                                id
+                            ))
+                        });
+                    }
+                    o
+                })
+        })
+    });
+
+    if kinds.len() > 1 {
+        c.create_action_row(|r| {
+            r.create_select_menu(|m| {
+                m.custom_id(&ids.filter)
+                    .placeholder(tr!(ctx, "search-filter"))
+                    .options(|o| {
+                        o.create_option(|o| o.label(tr!(ctx, "search-filter-all")).value("all"));
+                        for command in kinds {
+                            let label = search_index(command)
+                                .map(|index| kind(ctx, &index, false))
+                                .unwrap_or_default();
+                            o.create_option(|o| o.label(label).value(command));
+                        }
+                        o
+                    })
             })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Mount(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        });
     }
 
-    Ok(())
+    pager.buttons(c)
 }
 
-/// search for an in-game npc.
-#[poise::command(slash_command)]
-pub async fn npc(
+/// shows search results five at a time, letting the user page through them, filter them
+/// by content type and pick one to view in detail.
+///
+/// results should be grouped by content type, e.g. by sorting them on [`result_index`].
+async fn paginate(
     ctx: Context<'_>,
-    #[description = "the NPC's name"] name: String,
+    results: Vec<&SearchModel>,
+    language: Language,
 ) -> Result<(), Error> {
-    ctx.defer().await?;
+    let mut pager = Pager::new(ctx, results.len(), 5);
+    let ids = PageIds {
+        select: pager.id("select"),
+        filter: pager.id("filter"),
+        back: pager.id("back"),
+    };
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Npc)
-        .send()
-        .await?;
+    let mut kinds = results
+        .iter()
+        .map(|r| result_index(r).command)
+        .collect::<Vec<_>>();
+    kinds.dedup();
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("NPC not found!")
-                    .description("Kotonya couldn't find any NPCs with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Npc(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
+    let mut view = results.clone();
+
+    ctx.send(|b| {
+        let page = pager.items(&view);
+        for result in page {
+            b.embed(|e| summary_embed(ctx, e, result));
+        }
+
+        b.components(|c| page_components(ctx, c, &ids, &pager, page, &kinds))
+    })
+    .await?;
+
+    while let Some(press) = pager.next_press(ctx).await {
+        let press = match press {
+            PagerPress::Turned(press) => press,
+            PagerPress::Component(press) if press.data.custom_id == ids.filter => {
+                view = match press.data.values.first() {
+                    Some(kind) if kind != "all" => results
+                        .iter()
+                        .copied()
+                        .filter(|r| result_index(r).command == kind)
+                        .collect(),
+                    _ => results.clone(),
+                };
+                pager.reset(view.len());
+
+                press
             }
-            b
-        })
-        .await?;
+            PagerPress::Component(press) if press.data.custom_id == ids.select => {
+                let result = match press
+                    .data
+                    .values
+                    .first()
+                    .and_then(|v| v.parse::<usize>().ok())
+                    .and_then(|i| view.get(i))
+                {
+                    Some(r) => *r,
+                    None => continue,
+                };
+
+                // fetching the content row can take longer than Discord's response window.
+                press.defer(ctx).await?;
+                let detail = fetch_detail(ctx, result, language).await;
+
+                press
+                    .edit_original_interaction_response(ctx, |b| {
+                        b.embed(|e| match &detail {
+                            Some(detail) => detail_embed(ctx, e, result, detail),
+                            None => summary_embed(ctx, e, result),
+                        })
+                        .components(|c| {
+                            c.create_action_row(|r| {
+                                r.create_button(|b| {
+                                    b.custom_id(&ids.back).label(tr!(ctx, "search-back"))
+                                })
+                            })
+                        })
+                    })
+                    .await?;
+
+                continue;
+            }
+            PagerPress::Component(press) if press.data.custom_id == ids.back => press,
+            PagerPress::Component(_) => continue,
+        };
+
+        let page = pager.items(&view);
+
+        press
+            .create_interaction_response(ctx, |b| {
+                b.kind(serenity::InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|b| {
+                        for result in page {
+                            b.embed(|e| summary_embed(ctx, e, result));
+                        }
+
+                        b.components(|c| page_components(ctx, c, &ids, &pager, page, &kinds))
+                    })
+            })
+            .await?;
     }
 
     Ok(())
 }
 
-/// search for an in-game place.
-#[poise::command(slash_command)]
-pub async fn place(
-    ctx: Context<'_>,
-    #[description = "the place's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// how many lines [`paginate_list`] shows per page.
+const LIST_PAGE: usize = 15;
+
+/// pages through a plain list of lines, e.g. a character's collection, with the same
+/// navigation buttons as search results.
+pub async fn paginate_list(ctx: Context<'_>, title: String, lines: &[String]) -> Result<(), Error> {
+    let mut pager = Pager::new(ctx, lines.len(), LIST_PAGE);
+    let footer = |pager: &Pager| {
+        tr!(
+            ctx,
+            "search-page",
+            page: pager.page() + 1,
+            pages: pager.page_count()
+        )
+    };
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::PlaceName)
-        .send()
-        .await?;
+    ctx.send(|b| {
+        b.embed(|e| {
+            e.title(&title)
+                .description(pager.items(lines).join("\n"))
+                .footer(|f| f.text(footer(&pager)))
+        })
+        .components(|c| pager.buttons(c))
+    })
+    .await?;
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("place not found!")
-                    .description("Kotonya couldn't find any places with the specified name, nya!")
+    while let Some(press) = pager.next_press(ctx).await {
+        let press = match press {
+            PagerPress::Turned(press) => press,
+            PagerPress::Component(_) => continue,
+        };
+
+        press
+            .create_interaction_response(ctx, |b| {
+                b.kind(serenity::InteractionResponseType::UpdateMessage)
+                    .interaction_response_data(|b| {
+                        b.embed(|e| {
+                            e.title(&title)
+                                .description(pager.items(lines).join("\n"))
+                                .footer(|f| f.text(footer(&pager)))
+                        })
+                    })
             })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::PlaceName(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+            .await?;
     }
 
     Ok(())
 }
 
-/// search for an in-game quest.
-#[poise::command(slash_command)]
-pub async fn quest(
-    ctx: Context<'_>,
-    #[description = "the quest's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+/// the number of rows in a search subcommand's index, e.g. every mount in the game.
+///
+/// totals are cached in Redis for a day, since they only change with patches.
+pub async fn index_total(ctx: Context<'_>, command: &str) -> Option<u64> {
+    let search = search_index(command)?;
+    let key = format!("total:{}", search.sheet);
+    let mut con = ctx.data().client.get_async_connection().await.ok();
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Quest)
-        .send()
-        .await?;
+    if let Some(con) = &mut con {
+        let cached: Result<u64, RedisError> = con.get(&key).await;
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("quest not found!")
-                    .description("Kotonya couldn't find any quests with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Quest(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        if let Ok(cached) = cached {
+            return Some(cached);
+        }
     }
 
-    Ok(())
+    let total = match ctx.data().content.search_total(&[search.sheet]).await {
+        Ok(total) if total > 0 => total,
+        Ok(_) => return None,
+        Err(e) => {
+            warn!("failed to count {} rows: {}", search.sheet, e);
+            return None;
+        }
+    };
+
+    if let Some(con) = &mut con {
+        let result: Result<(), RedisError> = con.set_ex(&key, total, 60 * 60 * 24).await;
+
+        if let Err(e) = result {
+            warn!("failed to cache {} total: {}", search.sheet, e);
+        }
+    }
+
+    Some(total)
 }
 
-/// search for an in-game recipe.
-#[poise::command(slash_command)]
-pub async fn recipe(
+/// searches the given index and renders the results.
+async fn run_search(
     ctx: Context<'_>,
-    #[description = "the recipe's name"] name: String,
+    search: SearchIndex,
+    name: &str,
+    language: Option<Language>,
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
+    let language = language::resolve(ctx, language).await;
+
     let search_result = &ctx
         .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Recipe)
-        .send()
+        .content
+        .search(name, &[search.sheet], language)
         .await?;
 
     if search_result.results.is_empty() {
         ctx.send(|b| {
             b.embed(|e| {
-                e.title("recipe not found!")
-                    .description("Kotonya couldn't find any recipes with the specified name, nya!")
+                e.title(tr!(ctx, "search-not-found", kind: kind(ctx, &search, false)))
+                    .description(tr!(
+                        ctx,
+                        "search-not-found.description",
+                        kinds: kind(ctx, &search, true)
+                    ))
             })
         })
         .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
     } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Recipe(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        show_results(ctx, &search_result.results, language).await?;
     }
 
     Ok(())
 }
 
-/// search for an in-game status.
-#[poise::command(slash_command)]
-pub async fn status(
+/// shows a single result in detail, or pages through several of them grouped by content type.
+async fn show_results(
     ctx: Context<'_>,
-    #[description = "the status's name"] name: String,
+    results: &[SearchModel],
+    language: Language,
 ) -> Result<(), Error> {
-    ctx.defer().await?;
+    if let [result] = results {
+        let detail = fetch_detail(ctx, result, language).await;
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Status)
-        .send()
-        .await?;
-
-    if search_result.results.is_empty() {
         ctx.send(|b| {
-            b.embed(|e| {
-                e.title("status not found!")
-                    .description("Kotonya couldn't find any statuses with the specified name, nya!")
+            b.embed(|e| match &detail {
+                Some(detail) => detail_embed(ctx, e, result, detail),
+                None => summary_embed(ctx, e, result),
             })
         })
         .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
     } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Status(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        let mut results = results.iter().collect::<Vec<_>>();
+        results.sort_by_key(|r| result_index(r).command);
+
+        paginate(ctx, results, language).await?;
     }
 
     Ok(())
 }
 
-/// search for an in-game title.
-#[poise::command(slash_command)]
-pub async fn title(
+/// autocomplete suggestions for `key`, cached in Redis for a minute since Discord asks
+/// again on every keystroke. `fetch` only runs when they aren't cached.
+pub async fn cached_suggestions<E: std::fmt::Display>(
     ctx: Context<'_>,
-    #[description = "the title's name"] name: String,
-) -> Result<(), Error> {
-    ctx.defer().await?;
+    key: &str,
+    fetch: impl std::future::Future<Output = Result<Vec<String>, E>>,
+) -> Vec<String> {
+    let key = format!("autocomplete:{}", key);
+    let mut con = ctx.data().client.get_async_connection().await.ok();
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Title)
-        .send()
-        .await?;
+    if let Some(con) = &mut con {
+        let cached: Result<String, RedisError> = con.get(&key).await;
 
-    if search_result.results.is_empty() {
-        ctx.send(|b| {
-            b.embed(|e| {
-                e.title("title not found!")
-                    .description("Kotonya couldn't find any titles with the specified name, nya!")
-            })
-        })
-        .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
-    } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Title(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
+        if let Ok(cached) = cached {
+            return cached.lines().map(String::from).collect();
+        }
+    }
+
+    let names = match fetch.await {
+        Ok(names) => names,
+        Err(e) => {
+            warn!("failed to autocomplete {}: {}", key, e);
+            Vec::new()
+        }
+    };
+
+    if let Some(con) = &mut con {
+        if !names.is_empty() {
+            let result: Result<(), RedisError> = con.set_ex(&key, names.join("\n"), 60).await;
+
+            if let Err(e) = result {
+                warn!("failed to cache autocomplete results: {}", e);
             }
-            b
-        })
-        .await?;
+        }
     }
 
+    names
+}
+
+/// autocompletes a search subcommand's `name` argument with matching names from its index.
+async fn autocomplete_name(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let partial = partial.trim().to_lowercase();
+    let search = match search_index(&ctx.command().name) {
+        Some(s) if !partial.is_empty() => s,
+        _ => return Vec::new(),
+    };
+
+    let language = language::resolve(ctx, None).await;
+    let key = format!("{}:{}:{}", search.sheet, language.code(), partial);
+    let content = &ctx.data().content;
+
+    cached_suggestions(ctx, &key, async {
+        let search = content.search(&partial, &[search.sheet], language).await?;
+
+        Ok::<_, reqwest::Error>(
+            search
+                .results
+                .iter()
+                .map(|result| name_and_id(result).0.to_string())
+                .take(25)
+                .collect(),
+        )
+    })
+    .await
+}
+
+// the subcommands are added by `search_commands!`, which generates `search` itself.
+#[poise::command(slash_command, rename = "search")]
+async fn search_group(_: Context<'_>) -> Result<(), Error> {
     Ok(())
 }
 
-/// search for an in-game weather.
+/// search for anything in-game, across every index.
 #[poise::command(slash_command)]
-pub async fn weather(
+pub async fn all(
     ctx: Context<'_>,
-    #[description = "the weather's name"] name: String,
+    #[description = "what to search for"] query: String,
+    #[description = "the language to show results in"] language: Option<Language>,
 ) -> Result<(), Error> {
     ctx.defer().await?;
 
-    let search_result = &ctx
-        .data()
-        .api
-        .search()
-        .string(&name)
-        .index(xivapi::models::search::Index::Weather)
-        .send()
-        .await?;
+    let language = language::resolve(ctx, language).await;
+    let sheets = SEARCH_COMMANDS
+        .iter()
+        .filter_map(|c| search_index(c))
+        .map(|s| s.sheet)
+        .collect::<Vec<_>>();
+
+    let search_result = ctx.data().content.search(&query, &sheets, language).await?;
 
     if search_result.results.is_empty() {
         ctx.send(|b| {
             b.embed(|e| {
-                e.title("weather not found!")
-                    .description("Kotonya couldn't find any weathers with the specified name, nya!")
+                e.title(tr!(ctx, "search-nothing-found"))
+                    .description(tr!(ctx, "search-nothing-found.description"))
             })
         })
         .await?;
-    } else if search_result.results.len() > 5 {
-        paginate(ctx, search_result).await?;
     } else {
-        ctx.send(|b| {
-            for result in &search_result.results {
-                if let SearchModel::Weather(a) = result {
-                    b.embed(|f| {
-                        f.title(&a.name)
-                            .description(format!("ID: {}", &a.id))
-                            .thumbnail(format!("https://xivapi.com/{}", &a.icon))
-                    });
-                }
-            }
-            b
-        })
-        .await?;
+        show_results(ctx, &search_result.results, language).await?;
     }
 
     Ok(())
 }
+
+search_commands! {
+    /// search for an in-game achievement.
+    achievement: Achievement, "Achievement", "the achievement's name", [
+        ("search-field-points", "Points"),
+        ("search-field-category", "AchievementCategory.Name"),
+    ];
+
+    /// search for an in-game action.
+    action: Action, "Action", "the action's name", [
+        ("search-field-job", "ClassJob.Name"),
+        ("search-field-level", "ClassJobLevel"),
+        ("search-field-range", "Range"),
+        ("search-field-radius", "EffectRange"),
+    ];
+
+    /// search for an in-game emote.
+    emote: Emote, "Emote", "the emote's name", [
+        ("search-field-command", "TextCommand.Command"),
+        ("search-field-category", "EmoteCategory.Name"),
+    ];
+
+    /// search for an in-game enemy.
+    enemy: Enemy, "BNpcName", "the enemy's name", [];
+
+    /// search for an in-game fate.
+    fate: Fate, "Fate", "the FATE's name", [
+        ("search-field-level", "ClassJobLevel"),
+        ("search-field-max-level", "ClassJobLevelMax"),
+    ];
+
+    /// search for an in-game instanced content.
+    instance_content: InstanceContent, "InstanceContent", "the instanced content's name", [
+        ("search-field-type", "ContentFinderCondition.ContentType.Name"),
+        ("search-field-level", "ContentFinderCondition.ClassJobLevelRequired"),
+        ("search-field-item-level", "ContentFinderCondition.ItemLevelRequired"),
+        ("search-field-time-limit", "TimeLimitmin"),
+    ];
+
+    /// search for an in-game item.
+    item: Item, "Item", "the item's name", [
+        ("search-field-item-level", "LevelItem"),
+        ("search-field-equip-level", "LevelEquip"),
+        ("search-field-category", "ItemUICategory.Name"),
+        ("search-field-jobs", "ClassJobCategory.Name"),
+    ];
+
+    /// search for an in-game leve.
+    leve: Leve, "Leve", "the leve's name", [
+        ("search-field-level", "ClassJobLevel"),
+        ("search-field-jobs", "ClassJobCategory.Name"),
+        ("search-field-issued-at", "PlaceNameIssued.Name"),
+        ("search-field-gil-reward", "GilReward"),
+    ];
+
+    /// search for an in-game minion.
+    minion: Minion, "Companion", "the minion's name", [
+        ("search-field-behavior", "Behavior.Name"),
+    ];
+
+    /// search for an in-game mount.
+    mount: Mount, "Mount", "the mount's name", [];
+
+    /// search for an in-game npc.
+    npc: Npc, "ENpcResident", "the NPC's name", [("search-field-title", "Title")];
+
+    /// search for an in-game place.
+    place: PlaceName, "PlaceName", "the place's name", [];
+
+    /// search for an in-game quest.
+    quest: Quest, "Quest", "the quest's name", [
+        ("search-field-level", "ClassJobLevel0"),
+        ("search-field-genre", "JournalGenre.Name"),
+        ("search-field-location", "PlaceName.Name"),
+        ("search-field-gil-reward", "GilReward"),
+    ];
+
+    /// search for an in-game recipe.
+    recipe: Recipe, "Recipe", "the recipe's name", [
+        ("search-field-job", "ClassJob.Name"),
+        ("search-field-level", "RecipeLevelTable.ClassJobLevel"),
+        ("search-field-result", "ItemResult.Name"),
+        ("search-field-amount", "AmountResult"),
+    ];
+
+    /// search for an in-game status.
+    status: Status, "Status", "the status's name", [
+        ("search-field-max-stacks", "MaxStacks"),
+        ("search-field-permanent", "IsPermanent"),
+    ];
+
+    /// search for an in-game title.
+    title: Title, "Title", "the title's name", [
+        ("search-field-feminine", "NameFemale"),
+        ("search-field-prefix", "IsPrefix"),
+    ];
+
+    /// search for an in-game weather.
+    weather: Weather, "Weather", "the weather's name", [];
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn reads_potency_through_markup() {
+        let row = json!({
+            "Description_en": "Delivers an attack with a potency of <span style=\"color:#ff7b1a;\">220</span>.\nCombo Potency: 300"
+        });
+
+        assert_eq!(potency(&row), Some("220".to_string()));
+        assert_eq!(
+            potency(&json!({ "Description_en": "Increases movement speed." })),
+            None
+        );
+    }
+}
@@ -0,0 +1,308 @@
+use crate::language::Language;
+use futures::{stream, StreamExt};
+use serde::{de::DeserializeOwned, Deserialize};
+use serde_json::Value;
+use tracing::warn;
+use xivapi::models::{
+    character::CharacterResult, free_company::FreeCompanyResult, search::SearchResult,
+};
+
+/// a character matching a character search.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CharacterHit {
+    #[serde(rename = "ID")]
+    pub id: u64,
+    pub name: String,
+    /// the character's world, as XIVAPI formats it.
+    pub server: String,
+    pub avatar: String,
+}
+
+/// a free company matching a free company search.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FreeCompanyHit {
+    #[serde(rename = "ID")]
+    pub id: String,
+    pub name: String,
+    /// the free company's world, as XIVAPI formats it.
+    pub server: String,
+    /// the layers of the free company's crest, from back to front.
+    pub crest: Vec<String>,
+}
+
+/// a member of a free company, as listed on its Lodestone roster.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct FreeCompanyMember {
+    #[serde(rename = "ID")]
+    pub id: u64,
+    pub name: String,
+    /// the member's rank within the free company, named by the free company itself.
+    pub rank: String,
+    /// the member's world, as XIVAPI formats it.
+    pub server: String,
+    pub avatar: String,
+}
+
+/// a free company along with its members.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct FreeCompanyRoster {
+    free_company_members: Option<Vec<FreeCompanyMember>>,
+}
+
+/// the response of a character or free company search.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct LodestoneSearch<T> {
+    results: Vec<T>,
+}
+
+/// a client for the XIVAPI features `xivapi-rs` doesn't expose: game content rows
+/// (`/Item/{id}`, `/Action/{id}`, ...), responses in a language other than English and
+/// Lodestone search hits with their worlds and avatars.
+///
+/// responses are deserialized into `xivapi-rs`'s own models where it has one.
+#[derive(Clone)]
+pub struct ContentClient {
+    /// the underlying HTTP client.
+    http: reqwest::Client,
+    /// the XIVAPI token, if Kotonya is running with one.
+    key: Option<String>,
+}
+
+impl ContentClient {
+    pub fn new(key: Option<String>) -> Self {
+        Self {
+            http: reqwest::Client::new(),
+            key,
+        }
+    }
+
+    /// fetch an XIVAPI endpoint in the given language.
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        query: &[(&str, &str)],
+        language: Language,
+    ) -> Result<T, reqwest::Error> {
+        let mut request = self
+            .http
+            .get(format!("https://xivapi.com/{}", path))
+            .query(query)
+            .query(&[("language", language.code())]);
+
+        if let Some(key) = &self.key {
+            request = request.query(&[("private_key", key)]);
+        }
+
+        request.send().await?.error_for_status()?.json().await
+    }
+
+    /// fetch a single row of game content by its sheet name and ID.
+    pub async fn get(
+        &self,
+        content: &str,
+        id: &str,
+        language: Language,
+    ) -> Result<Value, reqwest::Error> {
+        self.fetch(&format!("{}/{}", content, id), &[], language)
+            .await
+    }
+
+    /// search the given indexes (by their sheet names) for a string.
+    pub async fn search(
+        &self,
+        string: &str,
+        indexes: &[&str],
+        language: Language,
+    ) -> Result<SearchResult, reqwest::Error> {
+        self.fetch(
+            "search",
+            &[("string", string), ("indexes", &indexes.join(","))],
+            language,
+        )
+        .await
+    }
+
+    /// the total number of rows in the given search indexes (by their sheet names).
+    pub async fn search_total(&self, indexes: &[&str]) -> Result<u64, reqwest::Error> {
+        let search: Value = self
+            .fetch(
+                "search",
+                &[("indexes", &indexes.join(",")), ("limit", "1")],
+                Language::English,
+            )
+            .await?;
+
+        Ok(search
+            .pointer("/Pagination/ResultsTotal")
+            .and_then(Value::as_u64)
+            .unwrap_or(0))
+    }
+
+    /// fetch a character by their Lodestone ID.
+    pub async fn character(
+        &self,
+        id: impl std::fmt::Display,
+        language: Language,
+    ) -> Result<CharacterResult, reqwest::Error> {
+        self.fetch(&format!("character/{}", id), &[], language)
+            .await
+    }
+
+    /// fetch a character by their Lodestone ID as a raw row, with the game data it refers
+    /// to (gear, materia, dyes, ...) expanded in place.
+    pub async fn character_details(
+        &self,
+        id: impl std::fmt::Display,
+        language: Language,
+    ) -> Result<Value, reqwest::Error> {
+        self.fetch(&format!("character/{}", id), &[("extended", "1")], language)
+            .await
+    }
+
+    /// fetch a character by their Lodestone ID as a raw, expanded row along with their
+    /// achievements, mounts and minions.
+    pub async fn character_collections(
+        &self,
+        id: impl std::fmt::Display,
+        language: Language,
+    ) -> Result<Value, reqwest::Error> {
+        self.fetch(
+            &format!("character/{}", id),
+            &[("extended", "1"), ("data", "AC,MIMO")],
+            language,
+        )
+        .await
+    }
+
+    /// search a Lodestone search endpoint by name on each of the given worlds (a few at a
+    /// time, to stay within XIVAPI's rate limit) and merge the hits, or search every world
+    /// at once if `worlds` is empty.
+    ///
+    /// worlds that fail to search are logged and skipped; it only fails if every world did.
+    async fn lodestone_search<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        name: &str,
+        worlds: &[&str],
+    ) -> Result<Vec<T>, reqwest::Error> {
+        if worlds.is_empty() {
+            let search: LodestoneSearch<T> = self
+                .fetch(path, &[("name", name)], Language::English)
+                .await?;
+
+            return Ok(search.results);
+        }
+
+        let searches: Vec<(&str, Result<LodestoneSearch<T>, reqwest::Error>)> =
+            stream::iter(worlds.iter().copied())
+                .map(|world| async move {
+                    let search = self
+                        .fetch(
+                            path,
+                            &[("name", name), ("server", world)],
+                            Language::English,
+                        )
+                        .await;
+
+                    (world, search)
+                })
+                .buffered(5)
+                .collect()
+                .await;
+
+        let mut hits = vec![];
+        let mut error = None;
+        let mut searched = false;
+        for (world, search) in searches {
+            match search {
+                Ok(search) => {
+                    hits.extend(search.results);
+                    searched = true;
+                }
+                Err(e) => {
+                    warn!("failed to search {} on {}: {}", path, world, e);
+                    error = Some(e);
+                }
+            }
+        }
+
+        match error {
+            Some(e) if !searched => Err(e),
+            _ => Ok(hits),
+        }
+    }
+
+    /// search for characters by name on the given worlds, or everywhere if there are none.
+    pub async fn character_search(
+        &self,
+        name: &str,
+        worlds: &[&str],
+    ) -> Result<Vec<CharacterHit>, reqwest::Error> {
+        self.lodestone_search("character/search", name, worlds)
+            .await
+    }
+
+    /// search for free companies by name on the given worlds, or everywhere if there are none.
+    pub async fn free_company_search(
+        &self,
+        name: &str,
+        worlds: &[&str],
+    ) -> Result<Vec<FreeCompanyHit>, reqwest::Error> {
+        self.lodestone_search("freecompany/search", name, worlds)
+            .await
+    }
+
+    /// fetch a free company by its Lodestone ID.
+    pub async fn free_company(
+        &self,
+        id: impl std::fmt::Display,
+        language: Language,
+    ) -> Result<FreeCompanyResult, reqwest::Error> {
+        self.fetch(&format!("freecompany/{}", id), &[], language)
+            .await
+    }
+
+    /// fetch every member of a free company, in the order of the Lodestone roster.
+    pub async fn free_company_members(
+        &self,
+        id: impl std::fmt::Display,
+    ) -> Result<Vec<FreeCompanyMember>, reqwest::Error> {
+        let roster: FreeCompanyRoster = self
+            .fetch(
+                &format!("freecompany/{}", id),
+                &[("data", "FCM")],
+                Language::English,
+            )
+            .await?;
+
+        Ok(roster.free_company_members.unwrap_or_default())
+    }
+}
+
+/// walk a (possibly nested, dot-separated) path into a content row.
+fn lookup<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(value, |current, key| current.get(key))
+}
+
+/// read a (possibly nested, dot-separated) field from a content row as a displayable string.
+///
+/// returns `None` for missing, null, empty or zero values, so callers can skip them.
+/// booleans aren't displayable without a locale, so they're read with [`flag`] instead.
+pub fn field(value: &Value, path: &str) -> Option<String> {
+    match lookup(value, path)? {
+        Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+        Value::Number(n) if n.as_f64() != Some(0.0) => Some(n.to_string()),
+        _ => None,
+    }
+}
+
+/// read a (possibly nested, dot-separated) boolean field from a content row.
+pub fn flag(value: &Value, path: &str) -> Option<bool> {
+    lookup(value, path)?.as_bool()
+}
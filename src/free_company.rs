@@ -0,0 +1,464 @@
+use crate::{
+    content::{field, ContentClient, FreeCompanyMember},
+    i18n::{tr_in, Translations},
+    language::{self, Language},
+    link, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands, RedisError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tracing::{info, warn};
+
+/// how long a free company's tag is cached, in seconds.
+const TAG_TTL: usize = 60 * 60 * 24;
+
+/// how often bound free companies' rosters are checked for changes.
+const FEED_INTERVAL: Duration = Duration::from_secs(3 * 60 * 60);
+
+/// the most changes posted for a free company at once, so the post fits in an embed.
+const FEED_LINES: usize = 40;
+
+/// the Redis key holding the set of guilds with a membership feed.
+pub const FEED_GUILDS_KEY: &str = "fc-feed:guilds";
+
+/// the Redis key caching a free company's tag.
+pub fn tag_key(id: impl std::fmt::Display) -> String {
+    format!("fc-tag:{}", id)
+}
+
+/// the Redis key holding the free companies bound to a guild, as a hash of ID to name.
+pub fn guild_key(id: impl std::fmt::Display) -> String {
+    format!("free-companies:{}", id)
+}
+
+/// the Redis key holding the ID of the channel a guild posts membership changes in.
+pub fn feed_key(id: impl std::fmt::Display) -> String {
+    format!("fc-feed:{}", id)
+}
+
+/// the Redis key holding a free company's roster as of the last check, as a hash of member
+/// ID to JSON [`RosterEntry`].
+pub fn roster_key(id: impl std::fmt::Display) -> String {
+    format!("fc-roster:{}", id)
+}
+
+/// the free companies bound to a guild, as `(id, name)` pairs sorted by name.
+pub async fn bound(
+    con: &mut Connection,
+    guild: serenity::GuildId,
+) -> Result<Vec<(String, String)>, RedisError> {
+    let bound: HashMap<String, String> = con.hgetall(guild_key(guild)).await?;
+    let mut bound: Vec<_> = bound.into_iter().collect();
+    bound.sort_by(|a, b| a.1.cmp(&b.1));
+
+    Ok(bound)
+}
+
+/// whether a user's character, by its ID and row from `character/{id}`, is in one of a
+/// guild's free companies.
+///
+/// only verified characters count, since anyone can link a character they don't own.
+pub async fn is_member(
+    con: &mut Connection,
+    guild: serenity::GuildId,
+    user: serenity::UserId,
+    id: &str,
+    row: &Value,
+) -> Result<bool, RedisError> {
+    if !link::is_verified(con, user, id).await? {
+        return Ok(false);
+    }
+
+    match field(row, "Character.FreeCompanyId") {
+        Some(fc) => con.hexists(guild_key(guild), fc).await,
+        None => Ok(false),
+    }
+}
+
+/// forget a free company's stored roster if no guild with a feed is bound to it anymore.
+///
+/// call after unbinding it or disabling a feed, so a later feed starts from a fresh roster
+/// instead of posting everything that changed in between.
+pub async fn forget_roster(con: &mut Connection, id: &str) -> Result<(), RedisError> {
+    let guilds: Vec<u64> = con.smembers(FEED_GUILDS_KEY).await?;
+
+    for guild in guilds {
+        if con.hexists(guild_key(guild), id).await? {
+            return Ok(());
+        }
+    }
+
+    con.del(roster_key(id)).await
+}
+
+/// a free company's tag, without the «» around it.
+///
+/// tags are cached in Redis for a day, since they rarely change and every character card
+/// needs one.
+pub async fn tag(
+    con: &mut Connection,
+    content: &ContentClient,
+    id: &str,
+) -> Result<Option<String>, Error> {
+    let cached: Option<String> = con.get(tag_key(id)).await?;
+    if cached.is_some() {
+        return Ok(cached);
+    }
+
+    let row = content.get("freecompany", id, Language::English).await?;
+    let tag = field(&row, "FreeCompany.Tag");
+
+    if let Some(tag) = &tag {
+        let _: () = con.set_ex(tag_key(id), tag, TAG_TTL).await?;
+    }
+
+    Ok(tag)
+}
+
+/// a member as stored in a free company's roster.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct RosterEntry {
+    name: String,
+    rank: String,
+}
+
+impl From<&FreeCompanyMember> for RosterEntry {
+    fn from(member: &FreeCompanyMember) -> Self {
+        Self {
+            name: member.name.clone(),
+            rank: member.rank.clone(),
+        }
+    }
+}
+
+/// a difference between two rosters of a free company.
+enum Change {
+    Joined(RosterEntry),
+    Left(RosterEntry),
+    Rank {
+        name: String,
+        from: String,
+        to: String,
+    },
+    Name {
+        from: String,
+        to: String,
+    },
+}
+
+impl Change {
+    /// where the change goes in a feed post: joins, leaves, rank changes then renames, each
+    /// by name.
+    fn order(&self) -> (u8, &str) {
+        match self {
+            Change::Joined(m) => (0, m.name.as_str()),
+            Change::Left(m) => (1, m.name.as_str()),
+            Change::Rank { name, .. } => (2, name.as_str()),
+            Change::Name { to, .. } => (3, to.as_str()),
+        }
+    }
+}
+
+/// every difference between a free company's previous roster and its current one.
+fn changes(
+    previous: &HashMap<String, RosterEntry>,
+    current: &HashMap<String, RosterEntry>,
+) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for (id, now) in current {
+        match previous.get(id) {
+            None => changes.push(Change::Joined(now.clone())),
+            Some(before) => {
+                if before.name != now.name {
+                    changes.push(Change::Name {
+                        from: before.name.clone(),
+                        to: now.name.clone(),
+                    });
+                }
+
+                if before.rank != now.rank {
+                    changes.push(Change::Rank {
+                        name: now.name.clone(),
+                        from: before.rank.clone(),
+                        to: now.rank.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    for (id, before) in previous {
+        if !current.contains_key(id) {
+            changes.push(Change::Left(before.clone()));
+        }
+    }
+
+    changes.sort_by(|a, b| a.order().cmp(&b.order()));
+    changes
+}
+
+/// a change as a line of a feed post.
+fn change_line(translations: &Translations, locale: Option<&str>, change: &Change) -> String {
+    match change {
+        Change::Joined(m) => tr_in!(
+            translations,
+            locale,
+            "free-company-feed.joined",
+            name: m.name.as_str(),
+            rank: m.rank.as_str()
+        ),
+        Change::Left(m) => tr_in!(
+            translations,
+            locale,
+            "free-company-feed.left",
+            name: m.name.as_str()
+        ),
+        Change::Rank { name, from, to } => tr_in!(
+            translations,
+            locale,
+            "free-company-feed.rank",
+            name: name.as_str(),
+            from: from.as_str(),
+            to: to.as_str()
+        ),
+        Change::Name { from, to } => tr_in!(
+            translations,
+            locale,
+            "free-company-feed.name",
+            from: from.as_str(),
+            to: to.as_str()
+        ),
+    }
+}
+
+/// fetch a free company's roster and store it, returning what changed since it was last
+/// stored, or `None` if it's never been stored before.
+async fn update_roster(
+    con: &mut Connection,
+    content: &ContentClient,
+    id: &str,
+) -> Result<Option<Vec<Change>>, Error> {
+    let members = content.free_company_members(id).await?;
+
+    // a private roster comes back empty, which would look like everyone leaving.
+    if members.is_empty() {
+        return Ok(Some(vec![]));
+    }
+
+    let stored: HashMap<String, String> = con.hgetall(roster_key(id)).await?;
+    let previous: HashMap<String, RosterEntry> = stored
+        .into_iter()
+        .filter_map(|(id, entry)| Some((id, serde_json::from_str(&entry).ok()?)))
+        .collect();
+    let current: HashMap<String, RosterEntry> = members
+        .iter()
+        .map(|m| (m.id.to_string(), RosterEntry::from(m)))
+        .collect();
+
+    let entries = current
+        .iter()
+        .map(|(id, entry)| Ok((id.clone(), serde_json::to_string(entry)?)))
+        .collect::<Result<Vec<_>, serde_json::Error>>()?;
+    // replaced in one transaction, so a crash in between can't leave an empty roster behind
+    // that would make everyone look like they just joined.
+    let _: () = redis::pipe()
+        .atomic()
+        .del(roster_key(id))
+        .hset_multiple(roster_key(id), &entries)
+        .query_async(con)
+        .await?;
+
+    if previous.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(changes(&previous, &current)))
+}
+
+/// check every bound free company with a feed for membership changes, and post them.
+async fn check_feeds(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    translations: &Translations,
+) -> Result<(), Error> {
+    let guilds: Vec<u64> = con.smembers(FEED_GUILDS_KEY).await?;
+
+    // several guilds can be bound to the same free company, but its roster is fetched once.
+    let mut feeds: HashMap<String, Vec<(serenity::GuildId, String)>> = HashMap::new();
+    for guild in guilds {
+        let guild = serenity::GuildId(guild);
+
+        let bound = match bound(con, guild).await {
+            Ok(bound) => bound,
+            Err(e) => {
+                warn!("failed to get the free companies bound to {}: {}", guild, e);
+                continue;
+            }
+        };
+
+        for (id, name) in bound {
+            feeds.entry(id).or_default().push((guild, name));
+        }
+    }
+
+    for (id, guilds) in feeds {
+        let changes = match update_roster(con, content, &id).await {
+            Ok(Some(changes)) if !changes.is_empty() => changes,
+            Ok(_) => continue,
+            Err(e) => {
+                warn!("failed to check the roster of free company {}: {}", id, e);
+                continue;
+            }
+        };
+
+        for (guild, name) in guilds {
+            let channel = match con.get(feed_key(guild)).await {
+                Ok(Some(channel)) => serenity::ChannelId(channel),
+                Ok(None) => continue,
+                Err(e) => {
+                    warn!("failed to get the feed channel of {}: {}", guild, e);
+                    continue;
+                }
+            };
+
+            let code: Option<String> = match con.get(language::guild_key(guild)).await {
+                Ok(code) => code,
+                Err(e) => {
+                    warn!("failed to get the language of {}: {}", guild, e);
+                    continue;
+                }
+            };
+            let locale = code.as_deref();
+            let mut lines: Vec<_> = changes
+                .iter()
+                .take(FEED_LINES)
+                .map(|c| change_line(translations, locale, c))
+                .collect();
+            if changes.len() > FEED_LINES {
+                lines.push(tr_in!(
+                    translations,
+                    locale,
+                    "free-company-feed.more",
+                    count: changes.len() - FEED_LINES
+                ));
+            }
+
+            let result = channel
+                .send_message(http, |m| {
+                    m.embed(|e| {
+                        e.title(tr_in!(
+                            translations,
+                            locale,
+                            "free-company-feed",
+                            name: name.as_str()
+                        ))
+                        .url(format!(
+                            "https://na.finalfantasyxiv.com/lodestone/freecompany/{}",
+                            id
+                        ))
+                        .description(lines.join("\n"))
+                    })
+                })
+                .await;
+
+            if let Err(e) = result {
+                warn!("failed to post membership changes in {}: {}", guild, e);
+            }
+        }
+
+        info!(
+            "posted {} membership changes of free company {}",
+            changes.len(),
+            id
+        );
+    }
+
+    Ok(())
+}
+
+/// check bound free companies for membership changes, forever.
+pub async fn feed_periodically(
+    http: Arc<serenity::Http>,
+    client: redis::Client,
+    content: ContentClient,
+    translations: Arc<Translations>,
+) {
+    // the previous rosters are stored, so checking right at startup catches changes made
+    // while Kotonya was down.
+    let mut interval = tokio::time::interval(FEED_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let result: Result<(), Error> = async {
+            let mut con = client.get_async_connection().await?;
+
+            check_feeds(&http, &mut con, &content, &translations).await
+        }
+        .await;
+
+        if let Err(e) = result {
+            warn!("failed to check free company feeds: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn unchanged_roster_has_no_changes() {
+        let roster = serde_json::from_value(json!({
+            "1": { "name": "Alphinaud Leveilleur", "rank": "Member" },
+        }))
+        .unwrap();
+
+        assert!(changes(&roster, &roster).is_empty());
+    }
+
+    #[test]
+    fn changes_are_grouped_and_sorted_by_name() {
+        let previous = serde_json::from_value(json!({
+            "1": { "name": "Yda Hext", "rank": "Member" },
+            "2": { "name": "Papalymo Totolymo", "rank": "Member" },
+            "3": { "name": "Thancred Waters", "rank": "Member" },
+            "4": { "name": "Minfilia Warde", "rank": "Member" },
+            "5": { "name": "Urianger Augurelt", "rank": "Member" },
+        }))
+        .unwrap();
+        let current = serde_json::from_value(json!({
+            "1": { "name": "Lyse Hext", "rank": "Member" },
+            "3": { "name": "Thancred Waters", "rank": "Officer" },
+            "4": { "name": "Minfilia Warde", "rank": "Officer" },
+            "5": { "name": "Urianger Augurelt", "rank": "Member" },
+            "6": { "name": "Y'shtola Rhul", "rank": "Member" },
+            "7": { "name": "Alisaie Leveilleur", "rank": "Recruit" },
+        }))
+        .unwrap();
+
+        let changes = changes(&previous, &current);
+
+        assert_eq!(
+            changes.iter().map(Change::order).collect::<Vec<_>>(),
+            vec![
+                (0, "Alisaie Leveilleur"),
+                (0, "Y'shtola Rhul"),
+                (1, "Papalymo Totolymo"),
+                (2, "Minfilia Warde"),
+                (2, "Thancred Waters"),
+                (3, "Lyse Hext"),
+            ]
+        );
+        assert!(matches!(
+            &changes[3],
+            Change::Rank { from, to, .. } if from == "Member" && to == "Officer"
+        ));
+        assert!(matches!(&changes[5], Change::Name { from, .. } if from == "Yda Hext"));
+    }
+}
@@ -0,0 +1,470 @@
+use crate::{
+    commands::character::average_item_level,
+    content::{field, ContentClient},
+    i18n::{tr_in, Translations},
+    language::{self, Language},
+    link, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands, AsyncIter};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tracing::{info, warn};
+
+/// how often every linked character is snapshotted.
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// how many snapshots are kept per character, about two months' worth.
+const MAX_SNAPSHOTS: isize = 120;
+
+/// achievement points are announced every time they pass a multiple of this.
+const ACHIEVEMENT_MILESTONE: u64 = 1000;
+
+/// the Redis key holding the set of guilds that announce progress.
+pub const ANNOUNCE_GUILDS_KEY: &str = "announce:guilds";
+
+/// the Redis key holding a user's snapshots of one of their characters, as a list of JSON
+/// snapshots from oldest to newest.
+pub fn history_key(user: impl std::fmt::Display, id: impl std::fmt::Display) -> String {
+    format!("history:{}:{}", user, id)
+}
+
+/// the Redis key holding the ID of the channel a guild announces progress in.
+pub fn announce_key(id: impl std::fmt::Display) -> String {
+    format!("announce:{}", id)
+}
+
+/// a character's progress at one point in time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// when the snapshot was taken, as a Unix timestamp.
+    pub time: u64,
+    /// every unlocked class and job's level, by `ClassJob` ID.
+    pub jobs: BTreeMap<u64, u64>,
+    /// the average item level of the equipped gear.
+    pub item_level: u64,
+    pub achievement_points: u64,
+    /// how many mounts and minions the character owns.
+    pub mounts: usize,
+    pub minions: usize,
+}
+
+impl Snapshot {
+    /// a snapshot of a character's row, as fetched by
+    /// [`ContentClient::character_collections`].
+    fn from_row(row: &Value, time: u64) -> Self {
+        let jobs = row
+            .pointer("/Character/ClassJobs")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|j| Some((j["JobID"].as_u64()?, j["Level"].as_u64()?)))
+            .filter(|(_, level)| *level > 0)
+            .collect();
+        let count = |collection: &str| row[collection].as_array().map_or(0, Vec::len);
+
+        Self {
+            time,
+            jobs,
+            item_level: row
+                .pointer("/Character/GearSet/Gear")
+                .map_or(0, average_item_level),
+            achievement_points: row
+                .pointer("/Achievements/Points")
+                .and_then(Value::as_u64)
+                .unwrap_or(0),
+            mounts: count("Mounts"),
+            minions: count("Minions"),
+        }
+    }
+
+    /// the sum of every class and job's level.
+    pub fn total_levels(&self) -> u64 {
+        self.jobs.values().sum()
+    }
+}
+
+/// every snapshot of a user's character, from oldest to newest.
+pub async fn snapshots(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+    id: &str,
+) -> Result<Vec<Snapshot>, Error> {
+    let snapshots: Vec<String> = con.lrange(history_key(user, id), 0, -1).await?;
+
+    Ok(snapshots
+        .iter()
+        .filter_map(|s| serde_json::from_str(s).ok())
+        .collect())
+}
+
+/// the newest snapshot of a user's character, if it's been snapshotted.
+pub async fn latest(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+    id: &str,
+) -> Result<Option<Snapshot>, Error> {
+    let snapshot: Option<String> = con.lindex(history_key(user, id), -1).await?;
+
+    Ok(snapshot.and_then(|s| serde_json::from_str(&s).ok()))
+}
+
+/// something worth announcing between two snapshots.
+#[derive(Debug, PartialEq, Eq)]
+enum Milestone {
+    /// a class or job gained levels.
+    LevelUp { job: String, level: u64 },
+    /// a class or job reached the level cap.
+    LevelCap { job: String, level: u64 },
+    /// achievement points passed a multiple of [`ACHIEVEMENT_MILESTONE`].
+    AchievementPoints(u64),
+}
+
+/// what changed between a character's previous snapshot and its current row.
+fn milestones(previous: &Snapshot, current: &Snapshot, row: &Value) -> Vec<Milestone> {
+    let mut milestones = vec![];
+    let jobs = row
+        .pointer("/Character/ClassJobs")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+
+    for job in jobs {
+        let (id, level) = match (job["JobID"].as_u64(), job["Level"].as_u64()) {
+            (Some(id), Some(level)) => (id, level),
+            _ => continue,
+        };
+        let name = match field(job, "UnlockedState.Name") {
+            Some(name) => name,
+            None => continue,
+        };
+
+        if level <= previous.jobs.get(&id).copied().unwrap_or(0) {
+            continue;
+        }
+
+        // a job at the level cap has no experience left to earn.
+        if job["ExpLevelMax"].as_u64().unwrap_or(0) == 0 {
+            milestones.push(Milestone::LevelCap { job: name, level });
+        } else {
+            milestones.push(Milestone::LevelUp { job: name, level });
+        }
+    }
+
+    let passed = current.achievement_points / ACHIEVEMENT_MILESTONE;
+    if passed > previous.achievement_points / ACHIEVEMENT_MILESTONE {
+        milestones.push(Milestone::AchievementPoints(passed * ACHIEVEMENT_MILESTONE));
+    }
+
+    milestones
+}
+
+/// a milestone as a line of an announcement.
+fn milestone_line(translations: &Translations, locale: Option<&str>, m: &Milestone) -> String {
+    match m {
+        Milestone::LevelUp { job, level } => tr_in!(
+            translations,
+            locale,
+            "history-announcement.level-up",
+            job: job.as_str(),
+            level: *level
+        ),
+        Milestone::LevelCap { job, level } => tr_in!(
+            translations,
+            locale,
+            "history-announcement.level-cap",
+            job: job.as_str(),
+            level: *level
+        ),
+        Milestone::AchievementPoints(points) => tr_in!(
+            translations,
+            locale,
+            "history-announcement.achievement-points",
+            points: *points
+        ),
+    }
+}
+
+/// add a snapshot to one of a user's characters' history.
+async fn record(
+    con: &mut Connection,
+    user: u64,
+    id: &str,
+    snapshot: &Snapshot,
+) -> Result<(), Error> {
+    let _: () = con
+        .rpush(history_key(user, id), serde_json::to_string(snapshot)?)
+        .await?;
+    let _: () = con.ltrim(history_key(user, id), -MAX_SNAPSHOTS, -1).await?;
+
+    Ok(())
+}
+
+/// post a character's milestones in every announcing guild the user is a member of,
+/// returning whether the user could be announced at all.
+///
+/// anyone can link any character, so only characters the user has verified are announced,
+/// and users who've hidden their characters never are.
+#[allow(clippy::too_many_arguments)]
+async fn announce(
+    http: &serenity::Http,
+    con: &mut Connection,
+    translations: &Translations,
+    guilds: &[u64],
+    user: u64,
+    id: &str,
+    row: &Value,
+    milestones: &[Milestone],
+) -> Result<bool, Error> {
+    if milestones.is_empty()
+        || !link::is_verified(con, user, id).await?
+        || link::is_hidden(con, user).await?
+    {
+        return Ok(false);
+    }
+
+    let name = field(row, "Character.Name").unwrap_or_default();
+
+    for guild in guilds {
+        let guild = serenity::GuildId(*guild);
+        let channel: Option<u64> = con.get(announce_key(guild)).await?;
+        let channel = match channel {
+            Some(channel) => serenity::ChannelId(channel),
+            None => continue,
+        };
+
+        if guild.member(http, user).await.is_err() {
+            continue;
+        }
+
+        let code: Option<String> = con.get(language::guild_key(guild)).await?;
+        let locale = code.as_deref();
+        let lines: Vec<_> = milestones
+            .iter()
+            .map(|m| milestone_line(translations, locale, m))
+            .collect();
+
+        let result = channel
+            .send_message(http, |m| {
+                m.embed(|e| {
+                    e.title(tr_in!(
+                        translations,
+                        locale,
+                        "history-announcement",
+                        name: name.as_str()
+                    ))
+                    .description(format!("<@{}>\n{}", user, lines.join("\n")));
+
+                    if let Some(avatar) = field(row, "Character.Avatar") {
+                        e.thumbnail(avatar);
+                    }
+
+                    e
+                })
+            })
+            .await;
+
+        if let Err(e) = result {
+            warn!("failed to announce in {}: {}", guild, e);
+        }
+    }
+
+    Ok(true)
+}
+
+/// snapshot every linked character, announcing what changed since the last snapshot.
+///
+/// links from before alts existed are found too, since [`link::migrate`] runs at startup.
+async fn track_all(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    translations: &Translations,
+) -> Result<usize, Error> {
+    let users: Vec<u64> = {
+        let mut iter: AsyncIter<String> = con.scan_match(link::characters_key("*")).await?;
+        let mut users = vec![];
+
+        while let Some(key) = iter.next_item().await {
+            if let Some(user) = key.strip_prefix("characters:").and_then(|u| u.parse().ok()) {
+                users.push(user);
+            }
+        }
+
+        users
+    };
+    let guilds: Vec<u64> = con.smembers(ANNOUNCE_GUILDS_KEY).await?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let mut tracked = 0;
+
+    // a character linked by several users is fetched and announced once.
+    let mut owners: BTreeMap<String, Vec<u64>> = BTreeMap::new();
+    for user in users {
+        for (id, _) in link::characters(con, user).await? {
+            owners.entry(id).or_default().push(user);
+        }
+    }
+
+    for (id, users) in owners {
+        let mut due = vec![];
+        for user in users {
+            let previous = latest(con, user, &id).await?;
+
+            // restarting Kotonya shouldn't snapshot everyone again.
+            if previous
+                .as_ref()
+                .is_some_and(|p| now.saturating_sub(p.time) < SNAPSHOT_INTERVAL.as_secs() / 2)
+            {
+                continue;
+            }
+
+            due.push((user, previous));
+        }
+
+        if due.is_empty() {
+            continue;
+        }
+
+        let row = match content.character_collections(&id, Language::English).await {
+            Ok(row) => row,
+            Err(e) => {
+                warn!("failed to snapshot character {}: {}", id, e);
+                continue;
+            }
+        };
+        let current = Snapshot::from_row(&row, now);
+        let mut announced = false;
+        tracked += 1;
+
+        for (user, previous) in due {
+            if let Err(e) = record(con, user, &id, &current).await {
+                warn!("failed to snapshot character {} for {}: {}", id, user, e);
+                continue;
+            }
+
+            let previous = match previous {
+                Some(previous) if !announced => previous,
+                _ => continue,
+            };
+            let milestones = milestones(&previous, &current, &row);
+
+            match announce(
+                http,
+                con,
+                translations,
+                &guilds,
+                user,
+                &id,
+                &row,
+                &milestones,
+            )
+            .await
+            {
+                Ok(done) => announced = done,
+                Err(e) => warn!("failed to announce character {}: {}", id, e),
+            }
+        }
+    }
+
+    Ok(tracked)
+}
+
+/// snapshot every linked character, forever.
+pub async fn track_periodically(
+    http: Arc<serenity::Http>,
+    client: redis::Client,
+    content: ContentClient,
+    translations: Arc<Translations>,
+) {
+    let mut interval = tokio::time::interval(SNAPSHOT_INTERVAL);
+
+    loop {
+        interval.tick().await;
+
+        let result: Result<usize, Error> = async {
+            let mut con = client.get_async_connection().await?;
+
+            track_all(&http, &mut con, &content, &translations).await
+        }
+        .await;
+
+        match result {
+            Ok(tracked) => info!("snapshotted {} characters", tracked),
+            Err(e) => warn!("failed to snapshot characters: {}", e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn announces_level_ups_and_the_level_cap() {
+        let row = json!({
+            "Character": {
+                "ClassJobs": [
+                    {
+                        "JobID": 19,
+                        "Level": 89,
+                        "ExpLevelMax": 3_000_000,
+                        "UnlockedState": { "Name": "Paladin" },
+                    },
+                    {
+                        "JobID": 24,
+                        "Level": 90,
+                        "ExpLevelMax": 0,
+                        "UnlockedState": { "Name": "White Mage" },
+                    },
+                    {
+                        "JobID": 20,
+                        "Level": 50,
+                        "ExpLevelMax": 1_000_000,
+                        "UnlockedState": { "Name": "Monk" },
+                    },
+                ]
+            }
+        });
+        let previous = Snapshot {
+            jobs: BTreeMap::from([(19, 88), (24, 89), (20, 50)]),
+            ..Snapshot::from_row(&row, 0)
+        };
+
+        assert_eq!(
+            milestones(&previous, &Snapshot::from_row(&row, 0), &row),
+            vec![
+                Milestone::LevelUp {
+                    job: "Paladin".to_string(),
+                    level: 89,
+                },
+                Milestone::LevelCap {
+                    job: "White Mage".to_string(),
+                    level: 90,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn announces_achievement_points_once_per_milestone() {
+        let row = json!({ "Achievements": { "Points": 3010 } });
+        let current = Snapshot::from_row(&row, 0);
+        let previous = |achievement_points| Snapshot {
+            achievement_points,
+            ..current.clone()
+        };
+
+        assert_eq!(
+            milestones(&previous(1990), &current, &row),
+            vec![Milestone::AchievementPoints(3000)]
+        );
+        assert!(milestones(&previous(3000), &current, &row).is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+use crate::{Context, Data, Error};
+use std::collections::HashMap;
+use tracing::warn;
+
+type FluentBundle = fluent::bundle::FluentBundle<
+    fluent::FluentResource,
+    intl_memoizer::concurrent::IntlLangMemoizer,
+>;
+
+/// the English catalog, used as a fallback for every other locale.
+const MAIN: (&str, &str) = ("en-US", include_str!("../translations/en-US.ftl"));
+
+/// the translated catalogs, keyed by their Discord locale.
+const OTHER: &[(&str, &str)] = &[
+    ("ja", include_str!("../translations/ja.ftl")),
+    ("de", include_str!("../translations/de.ftl")),
+    ("fr", include_str!("../translations/fr.ftl")),
+];
+
+/// Kotonya's message catalogs.
+pub struct Translations {
+    main: FluentBundle,
+    other: HashMap<String, FluentBundle>,
+}
+
+/// look up a message in the invoking user's locale, with optional `name: value` arguments.
+///
+/// `id` may point at an attribute with `message.attribute`.
+macro_rules! tr {
+    ($ctx:expr, $id:expr $(, $name:ident: $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut args = fluent::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)*
+
+        $crate::i18n::get($ctx, $id, &args)
+    }};
+}
+pub(crate) use tr;
+
+/// look up a message in the given locale, for messages sent outside of a command.
+macro_rules! tr_in {
+    ($translations:expr, $locale:expr, $id:expr $(, $name:ident: $value:expr)* $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut args = fluent::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)*
+
+        $crate::i18n::get_in($translations, $locale, $id, &args)
+    }};
+}
+pub(crate) use tr_in;
+
+fn bundle(locale: &str, source: &str) -> Result<FluentBundle, Error> {
+    let resource = fluent::FluentResource::try_new(source.to_string())
+        .map_err(|(_, e)| format!("failed to parse {} translations: {:?}", locale, e))?;
+
+    let mut bundle = FluentBundle::new_concurrent(vec![locale.parse()?]);
+    bundle.set_use_isolating(false);
+    bundle
+        .add_resource(resource)
+        .map_err(|e| format!("failed to add {} translations: {:?}", locale, e))?;
+
+    Ok(bundle)
+}
+
+/// parse every message catalog.
+pub fn read_ftl() -> Result<Translations, Error> {
+    let main = bundle(MAIN.0, MAIN.1)?;
+    let other = OTHER
+        .iter()
+        .map(|(locale, source)| Ok((locale.to_string(), bundle(locale, source)?)))
+        .collect::<Result<_, Error>>()?;
+
+    Ok(Translations { main, other })
+}
+
+fn format(
+    bundle: &FluentBundle,
+    id: &str,
+    attr: Option<&str>,
+    args: Option<&fluent::FluentArgs<'_>>,
+) -> Option<String> {
+    let message = bundle.get_message(id)?;
+    let pattern = match attr {
+        Some(attr) => message.get_attribute(attr)?.value(),
+        None => message.value()?,
+    };
+
+    Some(
+        bundle
+            .format_pattern(pattern, args, &mut vec![])
+            .into_owned(),
+    )
+}
+
+/// look up a message in the invoking user's locale, falling back to English.
+pub fn get(ctx: Context<'_>, id: &str, args: &fluent::FluentArgs<'_>) -> String {
+    get_in(&ctx.data().translations, ctx.locale(), id, args)
+}
+
+/// look up a message in the given locale, falling back to English.
+pub fn get_in(
+    translations: &Translations,
+    locale: Option<&str>,
+    id: &str,
+    args: &fluent::FluentArgs<'_>,
+) -> String {
+    let (id, attr) = match id.split_once('.') {
+        Some((id, attr)) => (id, Some(attr)),
+        None => (id, None),
+    };
+
+    locale
+        .and_then(|locale| format(translations.other.get(locale)?, id, attr, Some(args)))
+        .or_else(|| format(&translations.main, id, attr, Some(args)))
+        .unwrap_or_else(|| {
+            warn!("unknown fluent message identifier `{}`", id);
+            id.to_string()
+        })
+}
+
+/// register the translated command and parameter descriptions with Discord.
+///
+/// a command's description is the value of the message named after its full name
+/// (`character name` becomes `character-name`), and each parameter's description is the
+/// attribute named after the parameter. context menu commands are named after their
+/// function instead, and the message is their displayed name.
+pub fn apply_translations(
+    translations: &Translations,
+    commands: &mut [poise::Command<Data, Error>],
+) {
+    apply_translations_with_prefix(translations, commands, "");
+}
+
+fn apply_translations_with_prefix(
+    translations: &Translations,
+    commands: &mut [poise::Command<Data, Error>],
+    prefix: &str,
+) {
+    for command in commands {
+        let id = format!("{}{}", prefix, command.name);
+
+        for (locale, bundle) in &translations.other {
+            if let Some(description) = format(bundle, &id, None, None) {
+                // context menu commands have no description, so theirs is their name instead.
+                if command.context_menu_name.is_some() {
+                    command
+                        .name_localizations
+                        .insert(locale.clone(), description);
+                } else {
+                    command
+                        .description_localizations
+                        .insert(locale.clone(), description);
+                }
+            }
+
+            for parameter in &mut command.parameters {
+                if let Some(description) = format(bundle, &id, Some(&parameter.name), None) {
+                    parameter
+                        .description_localizations
+                        .insert(locale.clone(), description);
+                }
+            }
+        }
+
+        apply_translations_with_prefix(translations, &mut command.subcommands, &format!("{}-", id));
+    }
+}
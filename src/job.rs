@@ -0,0 +1,103 @@
+use crate::Context;
+
+/// a role and its jobs, by their `ClassJob` IDs.
+///
+/// a class that hasn't been upgraded to its job yet is listed under the job's ID.
+pub struct Role {
+    /// the role's name in the message catalog.
+    pub name: &'static str,
+    pub jobs: &'static [u64],
+}
+
+/// every role, in the order the game's character window lists them.
+pub const ROLES: &[Role] = &[
+    Role {
+        name: "job-role-tank",
+        jobs: &[19, 21, 32, 37],
+    },
+    Role {
+        name: "job-role-healer",
+        jobs: &[24, 28, 33, 40],
+    },
+    Role {
+        name: "job-role-melee",
+        jobs: &[20, 22, 30, 34, 39, 41],
+    },
+    Role {
+        name: "job-role-ranged",
+        jobs: &[23, 31, 38],
+    },
+    Role {
+        name: "job-role-caster",
+        jobs: &[25, 27, 35, 42],
+    },
+    Role {
+        name: "job-role-limited",
+        jobs: &[36],
+    },
+    Role {
+        name: "job-role-crafter",
+        jobs: &[8, 9, 10, 11, 12, 13, 14, 15],
+    },
+    Role {
+        name: "job-role-gatherer",
+        jobs: &[16, 17, 18],
+    },
+];
+
+/// every job's English name, by its `ClassJob` ID, for options that take a job.
+pub const NAMES: &[(u64, &str)] = &[
+    (19, "Paladin"),
+    (21, "Warrior"),
+    (32, "Dark Knight"),
+    (37, "Gunbreaker"),
+    (24, "White Mage"),
+    (28, "Scholar"),
+    (33, "Astrologian"),
+    (40, "Sage"),
+    (20, "Monk"),
+    (22, "Dragoon"),
+    (30, "Ninja"),
+    (34, "Samurai"),
+    (39, "Reaper"),
+    (41, "Viper"),
+    (23, "Bard"),
+    (31, "Machinist"),
+    (38, "Dancer"),
+    (25, "Black Mage"),
+    (27, "Summoner"),
+    (35, "Red Mage"),
+    (42, "Pictomancer"),
+    (36, "Blue Mage"),
+    (8, "Carpenter"),
+    (9, "Blacksmith"),
+    (10, "Armorer"),
+    (11, "Goldsmith"),
+    (12, "Leatherworker"),
+    (13, "Weaver"),
+    (14, "Alchemist"),
+    (15, "Culinarian"),
+    (16, "Miner"),
+    (17, "Botanist"),
+    (18, "Fisher"),
+];
+
+/// the job with the given English name, ignoring case.
+pub fn by_name(name: &str) -> Option<(u64, &'static str)> {
+    NAMES
+        .iter()
+        .copied()
+        .find(|(_, n)| n.eq_ignore_ascii_case(name))
+}
+
+/// autocompletes a job argument from every job's English name.
+pub async fn autocomplete(_: Context<'_>, partial: &str) -> Vec<String> {
+    let partial = partial.to_lowercase();
+
+    NAMES
+        .iter()
+        .map(|(_, name)| name.to_string())
+        .filter(|name| name.to_lowercase().starts_with(&partial))
+        .take(25)
+        .collect()
+}
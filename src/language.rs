@@ -0,0 +1,78 @@
+use crate::Context;
+use redis::{AsyncCommands, RedisError};
+
+/// a language XIVAPI can serve game data in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum Language {
+    #[name = "English"]
+    English,
+    #[name = "日本語"]
+    Japanese,
+    #[name = "Deutsch"]
+    German,
+    #[name = "Français"]
+    French,
+}
+
+impl Language {
+    /// the language code XIVAPI expects, which is also how the preference is stored.
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Japanese => "ja",
+            Language::German => "de",
+            Language::French => "fr",
+        }
+    }
+
+    /// the language with the given code, as returned by [`Language::code`].
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "en" => Some(Language::English),
+            "ja" => Some(Language::Japanese),
+            "de" => Some(Language::German),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+}
+
+/// the Redis key holding a user's language preference.
+pub fn user_key(id: impl std::fmt::Display) -> String {
+    format!("language:user:{}", id)
+}
+
+/// the Redis key holding a guild's language preference.
+pub fn guild_key(id: impl std::fmt::Display) -> String {
+    format!("language:guild:{}", id)
+}
+
+/// the language to serve game data in for this invocation.
+///
+/// an explicit `language` option wins, then the user's preference, then the guild's,
+/// falling back to English.
+pub async fn resolve(ctx: Context<'_>, language: Option<Language>) -> Language {
+    if let Some(language) = language {
+        return language;
+    }
+
+    let mut con = match ctx.data().client.get_async_connection().await {
+        Ok(con) => con,
+        Err(_) => return Language::English,
+    };
+
+    let mut keys = vec![user_key(ctx.author().id)];
+    if let Some(guild_id) = ctx.guild_id() {
+        keys.push(guild_key(guild_id));
+    }
+
+    for key in keys {
+        let stored: Result<String, RedisError> = con.get(&key).await;
+
+        if let Some(language) = stored.ok().and_then(|s| Language::from_code(&s)) {
+            return language;
+        }
+    }
+
+    Language::English
+}
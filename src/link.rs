@@ -0,0 +1,311 @@
+use crate::{history, nickname, roles, Context, Error};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands, AsyncIter, RedisError};
+use std::collections::{HashMap, HashSet};
+
+/// how long a verification code stays valid, in seconds.
+pub const VERIFICATION_TTL: usize = 60 * 60;
+
+/// the Redis key holding the ID of a user's main character.
+///
+/// this is the key `/link` has always written to, so links made before alts existed
+/// keep working as the user's main.
+pub fn main_key(id: impl std::fmt::Display) -> String {
+    id.to_string()
+}
+
+/// the Redis key holding every character a user has linked, as a hash of ID to name.
+pub fn characters_key(id: impl std::fmt::Display) -> String {
+    format!("characters:{}", id)
+}
+
+/// the Redis key holding the set of users who've linked a character, so its owners can be
+/// found without going through every user.
+pub fn owners_key(id: impl std::fmt::Display) -> String {
+    format!("owners:{}", id)
+}
+
+/// the Redis key holding the set of users with at least one linked character.
+pub const LINKED_USERS_KEY: &str = "linked-users";
+
+/// the Redis key holding the code a user has to put in a character's Lodestone bio to
+/// verify it.
+///
+/// each character gets its own key, so starting another link doesn't void a pending one.
+pub fn verification_key(user: impl std::fmt::Display, id: impl std::fmt::Display) -> String {
+    format!("verification:{}:{}", user, id)
+}
+
+/// the Redis key holding the set of character IDs a user has verified ownership of.
+pub fn verified_key(id: impl std::fmt::Display) -> String {
+    format!("verified:{}", id)
+}
+
+/// the Redis key set when a user has hidden their characters from other members.
+pub fn hidden_key(id: impl std::fmt::Display) -> String {
+    format!("hidden:{}", id)
+}
+
+/// whether a user has hidden their characters from other members.
+pub async fn is_hidden(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+) -> Result<bool, RedisError> {
+    con.exists(hidden_key(user)).await
+}
+
+/// every character a user has linked, as `(id, name)` pairs sorted by name.
+///
+/// a main linked before alts existed has no name stored, so its ID stands in for it.
+pub async fn characters(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+) -> Result<Vec<(String, String)>, RedisError> {
+    let mut characters: HashMap<String, String> = con.hgetall(characters_key(&user)).await?;
+    let main: Option<String> = con.get(main_key(&user)).await?;
+
+    if let Some(main) = main {
+        characters.entry(main.clone()).or_insert(main);
+    }
+
+    let mut characters: Vec<_> = characters.into_iter().collect();
+    characters.sort_by(|a, b| a.1.cmp(&b.1));
+
+    Ok(characters)
+}
+
+/// the ID of the character to use for a `self` command: the alt named by `alt` (by name or
+/// ID), or the user's main if there's none.
+pub async fn resolve(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+    alt: Option<&str>,
+) -> Result<Option<String>, RedisError> {
+    match alt {
+        Some(alt) => Ok(characters(con, user)
+            .await?
+            .into_iter()
+            .find(|(id, name)| id == alt || name.eq_ignore_ascii_case(alt))
+            .map(|(id, _)| id)),
+        None => con.get(main_key(user)).await,
+    }
+}
+
+/// link a character to a user, alongside any they've already linked. the first character a
+/// user links becomes their main.
+pub async fn add(
+    con: &mut Connection,
+    user: serenity::UserId,
+    id: u64,
+    name: &str,
+) -> Result<(), RedisError> {
+    let _: () = con.hset(characters_key(user), id, name).await?;
+    let _: () = con.sadd(owners_key(id), user.0).await?;
+    let _: () = con.sadd(LINKED_USERS_KEY, user.0).await?;
+    let _: () = con.set_nx(main_key(user), id).await?;
+
+    Ok(())
+}
+
+/// give every link made before alts existed, which only has a main key, an entry among the
+/// user's characters too, so everything going through `characters:*` sees it, then index
+/// every link by its character and user. returns how many links were migrated.
+pub async fn migrate(con: &mut Connection) -> Result<usize, RedisError> {
+    // main keys are the only ones named by a bare user ID.
+    let users: Vec<String> = {
+        let mut iter: AsyncIter<String> = con.scan_match("[0-9]*").await?;
+        let mut users = vec![];
+
+        while let Some(key) = iter.next_item().await {
+            if key.chars().all(|c| c.is_ascii_digit()) {
+                users.push(key);
+            }
+        }
+
+        users
+    };
+
+    let mut migrated = 0;
+    for user in users {
+        let main: Option<String> = con.get(main_key(&user)).await?;
+
+        if let Some(main) = main {
+            let added: bool = con.hset_nx(characters_key(&user), &main, &main).await?;
+            migrated += usize::from(added);
+        }
+    }
+
+    // links made before the owner index existed aren't in it yet.
+    let users: Vec<u64> = {
+        let mut iter: AsyncIter<String> = con.scan_match(characters_key("*")).await?;
+        let mut users = vec![];
+
+        while let Some(key) = iter.next_item().await {
+            if let Some(user) = key.strip_prefix("characters:").and_then(|u| u.parse().ok()) {
+                users.push(user);
+            }
+        }
+
+        users
+    };
+
+    for user in users {
+        for (id, _) in characters(con, user).await? {
+            let _: () = con.sadd(owners_key(id), user).await?;
+        }
+
+        let _: () = con.sadd(LINKED_USERS_KEY, user).await?;
+    }
+
+    Ok(migrated)
+}
+
+/// every user with at least one linked character.
+pub async fn linked_users(con: &mut Connection) -> Result<HashSet<u64>, RedisError> {
+    con.smembers(LINKED_USERS_KEY).await
+}
+
+/// every member of a guild, fetched a page at a time.
+///
+/// most members haven't linked anything, so callers should check them against
+/// [`linked_users`] before asking Redis anything else about them.
+pub async fn guild_members(
+    http: &serenity::Http,
+    guild: serenity::GuildId,
+) -> Result<Vec<serenity::Member>, serenity::Error> {
+    let mut members = vec![];
+    let mut after = None;
+
+    loop {
+        let page = guild.members(http, Some(1000), after).await?;
+        after = page.last().map(|m| m.user.id);
+        let last = page.len() < 1000;
+        members.extend(page);
+
+        if last {
+            break;
+        }
+    }
+
+    Ok(members)
+}
+
+/// whether a user has verified ownership of a character.
+pub async fn is_verified(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+    id: &str,
+) -> Result<bool, RedisError> {
+    con.sismember(verified_key(user), id).await
+}
+
+/// the members of a guild who've linked any of the given characters, by character.
+///
+/// goes through the characters' owners rather than the guild's members, so it costs a
+/// request per owner instead of listing the whole guild. only verified links count, and
+/// members who've hidden their characters are left out.
+pub async fn guild_owners(
+    ctx: &serenity::Context,
+    con: &mut Connection,
+    guild: serenity::GuildId,
+    characters: &[String],
+) -> Result<HashMap<String, serenity::UserId>, Error> {
+    if characters.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    let mut pipe = redis::pipe();
+    for id in characters {
+        pipe.smembers(owners_key(id));
+    }
+    let owners: Vec<Vec<u64>> = pipe.query_async(con).await?;
+
+    let mut visible: HashMap<u64, bool> = HashMap::new();
+    let mut linked = HashMap::new();
+
+    for (id, owners) in characters.iter().zip(owners) {
+        for user in owners {
+            let is_visible = match visible.get(&user) {
+                Some(is_visible) => *is_visible,
+                None => {
+                    let is_visible =
+                        !is_hidden(con, user).await? && guild.member(ctx, user).await.is_ok();
+                    visible.insert(user, is_visible);
+
+                    is_visible
+                }
+            };
+
+            if is_visible && is_verified(con, user, id).await? {
+                linked.insert(id.clone(), serenity::UserId(user));
+            }
+        }
+    }
+
+    Ok(linked)
+}
+
+/// autocompletes a character argument from the invoking user's linked characters.
+pub async fn autocomplete(ctx: Context<'_>, partial: &str) -> Vec<String> {
+    let mut con = match ctx.data().client.get_async_connection().await {
+        Ok(con) => con,
+        Err(_) => return vec![],
+    };
+    let partial = partial.to_lowercase();
+
+    characters(&mut con, ctx.author().id)
+        .await
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(_, name)| name)
+        .filter(|name| name.to_lowercase().starts_with(&partial))
+        .take(25)
+        .collect()
+}
+
+/// refresh everything Kotonya derives from the invoking user's links in the current guild:
+/// their roles and nickname.
+pub async fn changed(ctx: Context<'_>) {
+    roles::sync_author(ctx).await;
+    nickname::sync_author(ctx).await;
+}
+
+/// remove one of a user's linked characters, promoting another to main if it was their main.
+pub async fn unlink(
+    con: &mut Connection,
+    user: impl std::fmt::Display,
+    id: &str,
+) -> Result<(), RedisError> {
+    let _: () = con.hdel(characters_key(&user), id).await?;
+    let _: () = con.srem(owners_key(id), user.to_string()).await?;
+    let _: () = con.srem(verified_key(&user), id).await?;
+    let _: () = con.del(history::history_key(&user, id)).await?;
+    let _: () = con.del(verification_key(&user, id)).await?;
+
+    let main: Option<String> = con.get(main_key(&user)).await?;
+    if main.as_deref() == Some(id) {
+        let _: () = con.del(main_key(&user)).await?;
+
+        if let Some((next, _)) = characters(con, &user).await?.into_iter().next() {
+            let _: () = con.set(main_key(&user), next).await?;
+        }
+    }
+
+    if characters(con, &user).await?.is_empty() {
+        let _: () = con.srem(LINKED_USERS_KEY, user.to_string()).await?;
+    }
+
+    Ok(())
+}
+
+/// every Redis key holding a user's links and their visibility.
+///
+/// the pending verifications of each linked character are keyed by [`verification_key`].
+pub fn keys(id: impl std::fmt::Display) -> Vec<String> {
+    vec![
+        main_key(&id),
+        characters_key(&id),
+        verified_key(&id),
+        hidden_key(&id),
+    ]
+}
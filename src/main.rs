@@ -1,19 +1,31 @@
 use dotenvy::dotenv;
 use poise::{serenity_prelude as serenity, FrameworkOptions};
-use std::env;
+use std::{env, sync::Arc};
 use tracing::{error, info};
-use xivapi::XivApi;
 
+mod chooser;
 mod commands;
+mod content;
+mod free_company;
+mod history;
+mod i18n;
+mod job;
+mod language;
+mod link;
+mod nickname;
+mod roles;
+mod world;
 
 type Error = Box<dyn std::error::Error + Send + Sync>;
 type Context<'a> = poise::Context<'a, Data, Error>;
 /// user data for access in all commands.
 pub struct Data {
-    /// the XIVAPI client.
-    api: XivApi,
     /// the Redis database client.
     client: redis::Client,
+    /// the XIVAPI game content client.
+    content: content::ContentClient,
+    /// Kotonya's message catalogs.
+    translations: Arc<i18n::Translations>,
 }
 
 #[tokio::main]
@@ -22,27 +34,38 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt::init();
 
     let client = redis::Client::open("redis://127.0.0.1")?;
+    let migrated = link::migrate(&mut client.get_async_connection().await?).await?;
+    info!("migrated {} links from before alts existed", migrated);
 
-    let xivapi = match env::var("XIVAPI_TOKEN") {
-        Ok(s) => {
-            info!("running with a XIVAPI token!");
-            XivApi::with_key(s)
-        }
-        Err(_) => {
-            info!("running without a XIVAPI token!");
-            XivApi::new()
-        }
-    };
+    let token = env::var("XIVAPI_TOKEN").ok();
+    match &token {
+        Some(_) => info!("running with a XIVAPI token!"),
+        None => info!("running without a XIVAPI token!"),
+    }
+    let content = content::ContentClient::new(token);
+    let translations = Arc::new(i18n::read_ftl()?);
+
+    let mut commands = vec![
+        commands::ping::ping(),
+        commands::announcements::announcements(),
+        commands::character::character(),
+        commands::character::link(),
+        commands::character::unlink(),
+        commands::character::view_character(),
+        commands::free_company::free_company(),
+        commands::language::language(),
+        commands::leaderboard::leaderboard(),
+        commands::nickname::nicknames(),
+        commands::privacy::forget_me(),
+        commands::privacy::privacy(),
+        commands::roles::roles(),
+        commands::search::search(),
+    ];
+    i18n::apply_translations(&translations, &mut commands);
 
     let framework = poise::Framework::builder()
         .options(FrameworkOptions {
-            commands: vec![
-                commands::ping::ping(),
-                commands::character::character(),
-                commands::character::link(),
-                commands::free_company::free_company(),
-                commands::search::search(),
-            ],
+            commands,
             on_error: |error| {
                 Box::pin(async move {
                     match error {
@@ -68,9 +91,32 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .setup(move |ctx, _ready, framework| {
             Box::pin(async move {
                 poise::builtins::register_globally(ctx, &framework.options().commands).await?;
+                tokio::spawn(history::track_periodically(
+                    ctx.http.clone(),
+                    client.clone(),
+                    content.clone(),
+                    translations.clone(),
+                ));
+                tokio::spawn(free_company::feed_periodically(
+                    ctx.http.clone(),
+                    client.clone(),
+                    content.clone(),
+                    translations.clone(),
+                ));
+                tokio::spawn(nickname::sync_periodically(
+                    ctx.http.clone(),
+                    client.clone(),
+                    content.clone(),
+                ));
+                tokio::spawn(roles::sync_periodically(
+                    ctx.http.clone(),
+                    client.clone(),
+                    content.clone(),
+                ));
                 Ok(Data {
-                    api: xivapi,
                     client,
+                    content,
+                    translations,
                 })
             })
         });
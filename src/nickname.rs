@@ -0,0 +1,338 @@
+use crate::{
+    content::{field, ContentClient},
+    free_company,
+    language::Language,
+    link, Context, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands};
+use std::{collections::HashSet, sync::Arc, time::Duration};
+use tracing::{info, warn};
+
+/// how often every opted-in guild's nicknames are refreshed, which also picks up name
+/// changes on the Lodestone.
+const SYNC_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// the longest nickname Discord accepts.
+const MAX_LENGTH: usize = 32;
+
+/// the Redis key holding the set of guilds that sync nicknames.
+pub const GUILDS_KEY: &str = "nicknames:guilds";
+
+/// the Redis key holding the format a guild syncs nicknames in.
+pub fn nickname_key(id: impl std::fmt::Display) -> String {
+    format!("nicknames:{}", id)
+}
+
+/// the Redis key holding the nicknames Kotonya gave a guild's members, as a hash of user ID
+/// to nickname, so they can be taken away again once a member no longer qualifies.
+pub fn applied_key(id: impl std::fmt::Display) -> String {
+    format!("nicknames:applied:{}", id)
+}
+
+/// how a synced nickname is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum NicknameFormat {
+    #[name = "Character Name"]
+    Name,
+    #[name = "Character Name (World)"]
+    World,
+    #[name = "Character Name «TAG»"]
+    FreeCompany,
+}
+
+impl NicknameFormat {
+    /// how the format is stored.
+    pub fn key(self) -> &'static str {
+        match self {
+            NicknameFormat::Name => "name",
+            NicknameFormat::World => "world",
+            NicknameFormat::FreeCompany => "fc",
+        }
+    }
+
+    /// the format stored under the given key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "name" => Some(NicknameFormat::Name),
+            "world" => Some(NicknameFormat::World),
+            "fc" => Some(NicknameFormat::FreeCompany),
+            _ => None,
+        }
+    }
+}
+
+/// a guild's nickname format, if it syncs nicknames.
+pub async fn format(
+    con: &mut Connection,
+    guild: serenity::GuildId,
+) -> Result<Option<NicknameFormat>, Error> {
+    let key: Option<String> = con.get(nickname_key(guild)).await?;
+
+    Ok(key.as_deref().and_then(NicknameFormat::from_key))
+}
+
+/// the nickname for a user's main character, if they have one they've verified and haven't
+/// hidden.
+///
+/// an unverified link is only a claim, so renaming after it would let anyone pose as
+/// another player. the world or tag is dropped if it would make the nickname too long for
+/// Discord.
+async fn nickname(
+    con: &mut Connection,
+    content: &ContentClient,
+    format: NicknameFormat,
+    user: serenity::UserId,
+) -> Result<Option<String>, Error> {
+    let id = match link::resolve(con, user, None).await? {
+        Some(id) => id,
+        None => return Ok(None),
+    };
+    if !link::is_verified(con, user, &id).await? || link::is_hidden(con, user).await? {
+        return Ok(None);
+    }
+
+    let row = content.get("character", &id, Language::English).await?;
+    let name = match field(&row, "Character.Name") {
+        Some(name) => name,
+        None => return Ok(None),
+    };
+
+    let suffix = match format {
+        NicknameFormat::Name => None,
+        NicknameFormat::World => field(&row, "Character.Server").map(|w| format!(" ({})", w)),
+        NicknameFormat::FreeCompany => match field(&row, "Character.FreeCompanyId") {
+            Some(fc) => free_company::tag(con, content, &fc)
+                .await?
+                .map(|t| format!(" «{}»", t)),
+            None => None,
+        },
+    };
+
+    Ok(Some(match suffix {
+        Some(suffix) if name.chars().count() + suffix.chars().count() <= MAX_LENGTH => {
+            name + &suffix
+        }
+        _ => name,
+    }))
+}
+
+/// what to do with a member's nickname.
+#[derive(Debug, PartialEq, Eq)]
+enum Rename<'a> {
+    Keep,
+    Set(&'a str),
+    Reset,
+}
+
+/// what to do with a member's nickname, given their current one, the one Kotonya last gave
+/// them and the one they qualify for now.
+fn rename<'a>(current: Option<&str>, applied: Option<&str>, wanted: Option<&'a str>) -> Rename<'a> {
+    match wanted {
+        Some(wanted) if current == Some(wanted) => Rename::Keep,
+        Some(wanted) => Rename::Set(wanted),
+        // only the nickname Kotonya gave is taken away; one the member picked since is theirs.
+        None if applied.is_some() && current == applied => Rename::Reset,
+        None => Rename::Keep,
+    }
+}
+
+/// whether a request failed because Kotonya isn't allowed to make it, e.g. renaming the
+/// server owner or someone with a higher role.
+fn is_forbidden(error: &serenity::SerenityError) -> bool {
+    match error {
+        serenity::SerenityError::Http(e) => matches!(
+            &**e,
+            serenity::HttpError::UnsuccessfulRequest(r) if r.status_code.as_u16() == 403
+        ),
+        _ => false,
+    }
+}
+
+/// rename a member after their main character, returning `false` if Kotonya isn't allowed to.
+///
+/// members who no longer qualify, because they unlinked, hid or never verified their main,
+/// lose the nickname Kotonya gave them.
+async fn sync_member(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    format: NicknameFormat,
+    guild: serenity::GuildId,
+    member: &serenity::Member,
+) -> Result<bool, Error> {
+    let user = member.user.id;
+    let wanted = nickname(con, content, format, user).await?;
+    let applied: Option<String> = con.hget(applied_key(guild), user.0).await?;
+
+    let result = match rename(
+        member.nick.as_deref(),
+        applied.as_deref(),
+        wanted.as_deref(),
+    ) {
+        Rename::Keep => Ok(()),
+        Rename::Set(nickname) => guild
+            .edit_member(http, user, |m| m.nickname(nickname))
+            .await
+            .map(|_| ()),
+        // an empty nickname resets it to the member's username.
+        Rename::Reset => guild
+            .edit_member(http, user, |m| m.nickname(""))
+            .await
+            .map(|_| ()),
+    };
+
+    match result {
+        Ok(()) => {}
+        Err(e) if is_forbidden(&e) => return Ok(false),
+        Err(e) => return Err(e.into()),
+    }
+
+    let _: () = match wanted {
+        Some(nickname) => con.hset(applied_key(guild), user.0, nickname).await?,
+        None => con.hdel(applied_key(guild), user.0).await?,
+    };
+
+    Ok(true)
+}
+
+/// rename every linked member of a guild, and every member Kotonya renamed before, returning
+/// the members Kotonya wasn't allowed to rename.
+pub async fn sync_guild(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    guild: serenity::GuildId,
+) -> Result<Vec<serenity::UserId>, Error> {
+    let format = match format(con, guild).await? {
+        Some(format) => format,
+        None => return Ok(vec![]),
+    };
+    let linked = link::linked_users(con).await?;
+    let applied: HashSet<u64> = con.hkeys(applied_key(guild)).await?;
+    let mut forbidden = vec![];
+
+    for member in link::guild_members(http, guild).await? {
+        let user = member.user.id.0;
+
+        if member.user.bot || !(linked.contains(&user) || applied.contains(&user)) {
+            continue;
+        }
+
+        match sync_member(http, con, content, format, guild, &member).await {
+            Ok(true) => {}
+            Ok(false) => forbidden.push(member.user.id),
+            Err(e) => warn!("failed to sync nickname for {}: {}", member.user.id, e),
+        }
+    }
+
+    Ok(forbidden)
+}
+
+/// rename the invoking member after their links changed, if they're in a guild that syncs
+/// nicknames.
+pub async fn sync_author(ctx: Context<'_>) {
+    let guild = match ctx.guild_id() {
+        Some(guild) => guild,
+        None => return,
+    };
+
+    let result: Result<(), Error> = async {
+        let mut con = ctx.data().client.get_async_connection().await?;
+        let format = match format(&mut con, guild).await? {
+            Some(format) => format,
+            None => return Ok(()),
+        };
+        let http = &ctx.serenity_context().http;
+        let member = guild.member(http, ctx.author().id).await?;
+
+        if !sync_member(http, &mut con, &ctx.data().content, format, guild, &member).await? {
+            warn!("not allowed to rename {} in {}", ctx.author().id, guild);
+        }
+
+        Ok(())
+    }
+    .await;
+
+    if let Err(e) = result {
+        warn!("failed to sync nickname for {}: {}", ctx.author().id, e);
+    }
+}
+
+/// refresh the nicknames of every guild that syncs them, forever.
+pub async fn sync_periodically(
+    http: Arc<serenity::Http>,
+    client: redis::Client,
+    content: ContentClient,
+) {
+    let mut interval = tokio::time::interval(SYNC_INTERVAL);
+    // the first tick completes immediately, and there's no need to sync right at startup.
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let result: Result<(), Error> = async {
+            let mut con = client.get_async_connection().await?;
+            let guilds: Vec<u64> = con.smembers(GUILDS_KEY).await?;
+
+            for guild in guilds {
+                match sync_guild(&http, &mut con, &content, serenity::GuildId(guild)).await {
+                    Ok(forbidden) if forbidden.is_empty() => {
+                        info!("synced nicknames for {}", guild)
+                    }
+                    Ok(forbidden) => warn!(
+                        "synced nicknames for {}, but couldn't rename {} members",
+                        guild,
+                        forbidden.len()
+                    ),
+                    Err(e) => warn!("failed to sync nicknames for guild {}: {}", guild, e),
+                }
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            warn!("failed to sync nicknames: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renames_qualifying_members() {
+        assert_eq!(
+            rename(Some("Lyse"), None, Some("Lyse Hext")),
+            Rename::Set("Lyse Hext")
+        );
+        assert_eq!(
+            rename(None, None, Some("Lyse Hext")),
+            Rename::Set("Lyse Hext")
+        );
+        assert_eq!(
+            rename(Some("Lyse Hext"), None, Some("Lyse Hext")),
+            Rename::Keep
+        );
+    }
+
+    #[test]
+    fn resets_hidden_and_unlinked_members() {
+        // hidden, unverified and unlinked members all no longer qualify for a nickname.
+        assert_eq!(
+            rename(Some("Lyse Hext"), Some("Lyse Hext"), None),
+            Rename::Reset
+        );
+    }
+
+    #[test]
+    fn keeps_nicknames_kotonya_didnt_give() {
+        assert_eq!(rename(Some("Yda"), Some("Lyse Hext"), None), Rename::Keep);
+        assert_eq!(rename(Some("Yda"), None, None), Rename::Keep);
+        assert_eq!(rename(None, Some("Lyse Hext"), None), Rename::Keep);
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{
+    content::{field, ContentClient},
+    free_company,
+    language::Language,
+    link,
+    world::DATA_CENTERS,
+    Context, Error,
+};
+use poise::serenity_prelude as serenity;
+use redis::{aio::Connection, AsyncCommands};
+use serde_json::Value;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tracing::{info, warn};
+
+/// how often every configured guild's roles are refreshed.
+const SYNC_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// the Redis key holding the set of guilds with a role mapping.
+pub const GUILDS_KEY: &str = "roles:guilds";
+
+/// the Redis key holding a guild's role mapping, as a hash of mapping to role ID.
+///
+/// mappings are `verified`, `world:<world, data center or region>`, `gc:<grand company>`,
+/// `type:<tank, healer or dps>` and `free-company`, for members of the guild's free companies.
+pub fn roles_key(id: impl std::fmt::Display) -> String {
+    format!("roles:{}", id)
+}
+
+/// a Grand Company a character can be enlisted in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum GrandCompany {
+    #[name = "Maelstrom"]
+    Maelstrom,
+    #[name = "Order of the Twin Adder"]
+    TwinAdder,
+    #[name = "Immortal Flames"]
+    ImmortalFlames,
+}
+
+impl GrandCompany {
+    /// how the Grand Company is named in a mapping.
+    pub fn key(self) -> &'static str {
+        match self {
+            GrandCompany::Maelstrom => "maelstrom",
+            GrandCompany::TwinAdder => "twin-adder",
+            GrandCompany::ImmortalFlames => "immortal-flames",
+        }
+    }
+
+    /// the Grand Company with the given XIVAPI `GrandCompany` ID.
+    fn from_id(id: u64) -> Option<Self> {
+        match id {
+            1 => Some(GrandCompany::Maelstrom),
+            2 => Some(GrandCompany::TwinAdder),
+            3 => Some(GrandCompany::ImmortalFlames),
+            _ => None,
+        }
+    }
+}
+
+/// the part a job plays in a party.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, poise::ChoiceParameter)]
+pub enum CombatRole {
+    #[name = "tank"]
+    Tank,
+    #[name = "healer"]
+    Healer,
+    #[name = "DPS"]
+    Dps,
+}
+
+impl CombatRole {
+    /// how the role is named in a mapping.
+    pub fn key(self) -> &'static str {
+        match self {
+            CombatRole::Tank => "tank",
+            CombatRole::Healer => "healer",
+            CombatRole::Dps => "dps",
+        }
+    }
+
+    /// the role of a combat job, by its `ClassJob` ID.
+    fn of(job: u64) -> Option<Self> {
+        let role = crate::job::ROLES.iter().find(|r| r.jobs.contains(&job))?;
+
+        match role.name {
+            "job-role-tank" => Some(CombatRole::Tank),
+            "job-role-healer" => Some(CombatRole::Healer),
+            "job-role-melee" | "job-role-ranged" | "job-role-caster" => Some(CombatRole::Dps),
+            _ => None,
+        }
+    }
+}
+
+/// every mapping a verified character qualifies for.
+fn mappings(row: &Value, in_free_company: bool) -> Vec<String> {
+    let mut mappings = vec!["verified".to_string()];
+
+    if in_free_company {
+        mappings.push("free-company".to_string());
+    }
+
+    if let Some(world) = field(row, "Character.Server") {
+        mappings.push(format!("world:{}", world.to_lowercase()));
+
+        if let Some(dc) = DATA_CENTERS
+            .iter()
+            .find(|dc| dc.worlds.iter().any(|w| w.eq_ignore_ascii_case(&world)))
+        {
+            mappings.push(format!("world:{}", dc.name.to_lowercase()));
+            mappings.push(format!("world:{}", dc.region.to_lowercase()));
+        }
+    }
+
+    if let Some(gc) = row
+        .pointer("/Character/GrandCompany/NameID")
+        .and_then(Value::as_u64)
+        .and_then(GrandCompany::from_id)
+    {
+        mappings.push(format!("gc:{}", gc.key()));
+    }
+
+    if let Some(role) = row
+        .pointer("/Character/ActiveClassJob/JobID")
+        .and_then(Value::as_u64)
+        .and_then(CombatRole::of)
+    {
+        mappings.push(format!("type:{}", role.key()));
+    }
+
+    mappings
+}
+
+/// give a member the mapped roles their main character qualifies for, and take away the
+/// mapped roles it doesn't.
+///
+/// roles that aren't part of the mapping are left alone.
+async fn sync_member(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    mapping: &HashMap<String, u64>,
+    member: &mut serenity::Member,
+) -> Result<(), Error> {
+    let user = member.user.id;
+    let main = match link::resolve(con, user, None).await? {
+        Some(id) => Some((link::is_verified(con, user, &id).await?, id)),
+        None => None,
+    };
+    let qualifies = match main {
+        Some((true, id)) => {
+            let row = content.get("character", &id, Language::English).await?;
+            let in_free_company =
+                free_company::is_member(con, member.guild_id, user, &id, &row).await?;
+
+            mappings(&row, in_free_company)
+        }
+        // anyone can link any character, so an unverified main is only a claim and earns
+        // nothing derived from it.
+        _ => vec![],
+    };
+
+    let mut wanted: Vec<_> = mapping
+        .iter()
+        .filter(|(k, _)| qualifies.contains(k))
+        .map(|(_, r)| serenity::RoleId(*r))
+        .collect();
+    wanted.sort();
+    wanted.dedup();
+
+    let add: Vec<_> = wanted
+        .iter()
+        .copied()
+        .filter(|r| !member.roles.contains(r))
+        .collect();
+    let mut remove: Vec<_> = mapping
+        .values()
+        .map(|r| serenity::RoleId(*r))
+        .filter(|r| member.roles.contains(r) && !wanted.contains(r))
+        .collect();
+    remove.sort();
+    remove.dedup();
+
+    if !add.is_empty() {
+        member.add_roles(http, &add).await?;
+    }
+
+    if !remove.is_empty() {
+        member.remove_roles(http, &remove).await?;
+    }
+
+    Ok(())
+}
+
+/// sync a single member's roles in a guild.
+pub async fn sync_user(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    guild: serenity::GuildId,
+    user: serenity::UserId,
+) -> Result<(), Error> {
+    let mapping: HashMap<String, u64> = con.hgetall(roles_key(guild)).await?;
+
+    if mapping.is_empty() {
+        return Ok(());
+    }
+
+    let mut member = guild.member(http, user).await?;
+
+    sync_member(http, con, content, &mapping, &mut member).await
+}
+
+/// sync every member of a guild who has linked a character or holds a mapped role, returning
+/// how many were synced.
+pub async fn sync_guild(
+    http: &serenity::Http,
+    con: &mut Connection,
+    content: &ContentClient,
+    guild: serenity::GuildId,
+) -> Result<usize, Error> {
+    let mapping: HashMap<String, u64> = con.hgetall(roles_key(guild)).await?;
+    let managed: Vec<_> = mapping.values().map(|r| serenity::RoleId(*r)).collect();
+    let mut synced = 0;
+
+    if mapping.is_empty() {
+        return Ok(0);
+    }
+
+    let linked = link::linked_users(con).await?;
+
+    for mut member in link::guild_members(http, guild).await? {
+        if member.user.bot {
+            continue;
+        }
+
+        if !linked.contains(&member.user.id.0) && !member.roles.iter().any(|r| managed.contains(r))
+        {
+            continue;
+        }
+
+        match sync_member(http, con, content, &mapping, &mut member).await {
+            Ok(()) => synced += 1,
+            Err(e) => warn!("failed to sync roles for {}: {}", member.user.id, e),
+        }
+    }
+
+    Ok(synced)
+}
+
+/// refresh the invoking member's roles after their links changed, if they're in a guild.
+pub async fn sync_author(ctx: Context<'_>) {
+    let guild = match ctx.guild_id() {
+        Some(guild) => guild,
+        None => return,
+    };
+
+    let result: Result<(), Error> = async {
+        let mut con = ctx.data().client.get_async_connection().await?;
+
+        sync_user(
+            &ctx.serenity_context().http,
+            &mut con,
+            &ctx.data().content,
+            guild,
+            ctx.author().id,
+        )
+        .await
+    }
+    .await;
+
+    if let Err(e) = result {
+        warn!("failed to sync roles for {}: {}", ctx.author().id, e);
+    }
+}
+
+/// refresh the roles of every guild with a mapping, forever.
+pub async fn sync_periodically(
+    http: Arc<serenity::Http>,
+    client: redis::Client,
+    content: ContentClient,
+) {
+    let mut interval = tokio::time::interval(SYNC_INTERVAL);
+    // the first tick completes immediately, and there's no need to sync right at startup.
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        let result: Result<(), Error> = async {
+            let mut con = client.get_async_connection().await?;
+            let guilds: Vec<u64> = con.smembers(GUILDS_KEY).await?;
+
+            for guild in guilds {
+                match sync_guild(&http, &mut con, &content, serenity::GuildId(guild)).await {
+                    Ok(synced) => info!("synced roles for {} members of {}", synced, guild),
+                    Err(e) => warn!("failed to sync roles for guild {}: {}", guild, e),
+                }
+            }
+
+            Ok(())
+        }
+        .await;
+
+        if let Err(e) = result {
+            warn!("failed to sync roles: {}", e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn maps_world_grand_company_and_role() {
+        let row = json!({
+            "Character": {
+                "Server": "Gilgamesh",
+                "GrandCompany": { "NameID": 2 },
+                "ActiveClassJob": { "JobID": 24 },
+            }
+        });
+
+        assert_eq!(
+            mappings(&row, false),
+            vec![
+                "verified",
+                "world:gilgamesh",
+                "world:aether",
+                "world:north america",
+                "gc:twin-adder",
+                "type:healer",
+            ]
+        );
+    }
+
+    #[test]
+    fn maps_free_company_membership() {
+        let row = json!({ "Character": {} });
+
+        assert_eq!(mappings(&row, true), vec!["verified", "free-company"]);
+        assert_eq!(mappings(&row, false), vec!["verified"]);
+    }
+
+    #[test]
+    fn skips_unmapped_jobs_and_grand_companies() {
+        let row = json!({
+            "Character": {
+                "Server": "Nowhere",
+                "GrandCompany": { "NameID": 0 },
+                "ActiveClassJob": { "JobID": 36 },
+            }
+        });
+
+        assert_eq!(mappings(&row, false), vec!["verified", "world:nowhere"]);
+    }
+}
@@ -0,0 +1,242 @@
+use crate::Context;
+use std::str::FromStr;
+use xivapi::prelude::World;
+
+/// a data center, along with the region it belongs to and its worlds.
+pub struct DataCenter {
+    pub name: &'static str,
+    pub region: &'static str,
+    pub worlds: &'static [&'static str],
+}
+
+/// every public data center and its worlds.
+pub const DATA_CENTERS: &[DataCenter] = &[
+    DataCenter {
+        name: "Aether",
+        region: "North America",
+        worlds: &[
+            "Adamantoise",
+            "Cactuar",
+            "Faerie",
+            "Gilgamesh",
+            "Jenova",
+            "Midgardsormr",
+            "Sargatanas",
+            "Siren",
+        ],
+    },
+    DataCenter {
+        name: "Crystal",
+        region: "North America",
+        worlds: &[
+            "Balmung",
+            "Brynhildr",
+            "Coeurl",
+            "Diabolos",
+            "Goblin",
+            "Malboro",
+            "Mateus",
+            "Zalera",
+        ],
+    },
+    DataCenter {
+        name: "Dynamis",
+        region: "North America",
+        worlds: &[
+            "Cuchulainn",
+            "Golem",
+            "Halicarnassus",
+            "Kraken",
+            "Maduin",
+            "Marilith",
+            "Rafflesia",
+            "Seraph",
+        ],
+    },
+    DataCenter {
+        name: "Primal",
+        region: "North America",
+        worlds: &[
+            "Behemoth",
+            "Excalibur",
+            "Exodus",
+            "Famfrit",
+            "Hyperion",
+            "Lamia",
+            "Leviathan",
+            "Ultros",
+        ],
+    },
+    DataCenter {
+        name: "Chaos",
+        region: "Europe",
+        worlds: &[
+            "Cerberus",
+            "Louisoix",
+            "Moogle",
+            "Omega",
+            "Phantom",
+            "Ragnarok",
+            "Sagittarius",
+            "Spriggan",
+        ],
+    },
+    DataCenter {
+        name: "Light",
+        region: "Europe",
+        worlds: &[
+            "Alpha",
+            "Lich",
+            "Odin",
+            "Phoenix",
+            "Raiden",
+            "Shiva",
+            "Twintania",
+            "Zodiark",
+        ],
+    },
+    DataCenter {
+        name: "Materia",
+        region: "Oceania",
+        worlds: &["Bismarck", "Ravana", "Sephirot", "Sophia", "Zurvan"],
+    },
+    DataCenter {
+        name: "Elemental",
+        region: "Japan",
+        worlds: &[
+            "Aegis",
+            "Atomos",
+            "Carbuncle",
+            "Garuda",
+            "Gungnir",
+            "Kujata",
+            "Tonberry",
+            "Typhon",
+        ],
+    },
+    DataCenter {
+        name: "Gaia",
+        region: "Japan",
+        worlds: &[
+            "Alexander",
+            "Bahamut",
+            "Durandal",
+            "Fenrir",
+            "Ifrit",
+            "Ridill",
+            "Tiamat",
+            "Ultima",
+        ],
+    },
+    DataCenter {
+        name: "Mana",
+        region: "Japan",
+        worlds: &[
+            "Anima",
+            "Asura",
+            "Chocobo",
+            "Hades",
+            "Ixion",
+            "Masamune",
+            "Pandaemonium",
+            "Titan",
+        ],
+    },
+    DataCenter {
+        name: "Meteor",
+        region: "Japan",
+        worlds: &[
+            "Belias",
+            "Mandragora",
+            "Ramuh",
+            "Shinryu",
+            "Unicorn",
+            "Valefor",
+            "Yojimbo",
+            "Zeromus",
+        ],
+    },
+];
+
+/// every world Kotonya knows about, skipping any the XIVAPI client can't parse yet.
+pub fn worlds() -> impl Iterator<Item = &'static str> {
+    DATA_CENTERS
+        .iter()
+        .flat_map(|dc| dc.worlds.iter().copied())
+        .filter(|w| World::from_str(w).is_ok())
+}
+
+/// every region with a public data center.
+pub fn regions() -> Vec<&'static str> {
+    let mut regions: Vec<_> = DATA_CENTERS.iter().map(|dc| dc.region).collect();
+    regions.dedup();
+
+    regions
+}
+
+/// the worlds covered by a world, data center or region name, ignoring case.
+///
+/// returns `None` if the name doesn't match any of them.
+pub fn scope(name: &str) -> Option<Vec<&'static str>> {
+    if let Some(world) = worlds().find(|w| w.eq_ignore_ascii_case(name)) {
+        return Some(vec![world]);
+    }
+
+    let worlds: Vec<_> = DATA_CENTERS
+        .iter()
+        .filter(|dc| dc.name.eq_ignore_ascii_case(name) || dc.region.eq_ignore_ascii_case(name))
+        .flat_map(|dc| dc.worlds.iter().copied())
+        .filter(|w| World::from_str(w).is_ok())
+        .collect();
+
+    if worlds.is_empty() {
+        None
+    } else {
+        Some(worlds)
+    }
+}
+
+/// autocompletes a world, data center or region argument.
+pub async fn autocomplete(_: Context<'_>, partial: &str) -> impl Iterator<Item = String> {
+    let partial = partial.to_lowercase();
+
+    regions()
+        .into_iter()
+        .chain(DATA_CENTERS.iter().map(|dc| dc.name))
+        .chain(worlds())
+        .filter(move |name| name.to_lowercase().starts_with(&partial))
+        .take(25)
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scopes_a_world_to_itself() {
+        assert_eq!(scope("gilgamesh"), Some(vec!["Gilgamesh"]));
+    }
+
+    #[test]
+    fn scopes_a_data_center_to_its_worlds() {
+        let worlds = scope("AETHER").unwrap();
+
+        assert!(worlds.contains(&"Gilgamesh"));
+        assert!(worlds.iter().all(|w| DATA_CENTERS[0].worlds.contains(w)));
+    }
+
+    #[test]
+    fn scopes_a_region_to_every_data_center_in_it() {
+        let worlds = scope("japan").unwrap();
+
+        assert!(worlds.contains(&"Tonberry"));
+        assert!(worlds.contains(&"Bahamut"));
+        assert!(!worlds.contains(&"Gilgamesh"));
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(scope("Nowhere"), None);
+    }
+}
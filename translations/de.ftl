@@ -0,0 +1,450 @@
+pong = Pong!
+xivapi-error = XIVAPI-Fehler!
+invalid-world = Ungültige Welt!
+invalid-id = Ungültige ID!
+    .description = `{ $id }` ist keine Lodestone-ID. Lodestone-IDs bestehen nur aus Ziffern.
+
+## character
+
+character-not-found = Dein Charakter wurde nicht gefunden!
+    .id = Kotonya konnte keinen Charakter mit der angegebenen ID finden, nya!
+    .name = Kotonya konnte keinen Charakter mit dem angegebenen Namen finden, nya!
+character-not-linked = Dein Charakter konnte nicht abgerufen werden!
+    .description = Mit deinem Discord-Konto ist kein Charakter verknüpft. Bitte verknüpfe deinen Charakter mit `/link character <Name/ID>`!
+    .alt = Du hast keinen Charakter namens `{ $alt }` verknüpft. Mit `/link list` siehst du deine verknüpften Charaktere!
+    .user = { $user } hat keinen Charakter mit dem Discord-Konto verknüpft.
+character-lodestone-id = Lodestone-ID: `{ $id }`
+character-information = Informationen
+character-city-state = Stadtstaat
+character-nameday = Namenstag
+character-world = Welt: { $world }
+character-free-company = Freie Gesellschaft
+    .button = Freie Gesellschaft
+character-pick = Wähle deinen Charakter
+character-unverified = ⚠️ Diese Verknüpfung ist noch nicht verifiziert. Nutze `/link verify`, sobald der Code in deinem Lodestone-Profiltext steht.
+character-hidden = Dieser Charakter ist verborgen!
+    .description = { $user } teilt den eigenen Charakter nicht mit anderen Mitgliedern.
+link-successful = Verknüpfung erfolgreich!
+    .description = `{ $user }` wurde erfolgreich mit `{ $character }` verknüpft!
+link-verification = Verifiziere deinen Charakter
+    .description = Füge `{ $code }` irgendwo in deinen Lodestone-Profiltext ein und nutze innerhalb einer Stunde `/link verify`. Danach kannst du ihn wieder entfernen!
+link-not-pending = Nichts zu verifizieren!
+    .description = Du hast keine ausstehende Verknüpfung. Bitte nutze zuerst `/link character <Name/ID>`!
+link-verify-failed = Verifizierung fehlgeschlagen!
+    .description = `{ $character }`: Kotonya konnte `{ $code }` nicht im Lodestone-Profiltext finden, nya!
+    .retry = Der Lodestone kann ein paar Minuten zum Aktualisieren brauchen, versuche es also gleich noch einmal.
+link-verified = Verknüpfung verifiziert!
+    .description = `{ $character }` ist jetzt als dein Charakter verifiziert!
+linked-characters = Deine verknüpften Charaktere
+    .footer = ⭐ Hauptcharakter · ⚠️ nicht verifiziert
+link-main-updated = Hauptcharakter aktualisiert!
+    .description = `{ $character }` ist jetzt dein Hauptcharakter!
+unlink-successful = Verknüpfung aufgehoben!
+    .description = `{ $character }` ist nicht mehr mit deinem Discord-Konto verknüpft.
+
+character-back = Zurück zum Charakter
+character-job-levels = Jobs von { $name }
+    .button = Jobs
+character-job = **{ $job }** St. { $level } ({ $progress } %)
+    .max = **{ $job }** St. { $level }
+
+job-role-tank = Verteidiger
+job-role-healer = Heiler
+job-role-melee = Nahkampf-Angreifer
+job-role-ranged = Physische Fernkampf-Angreifer
+job-role-caster = Magische Fernkampf-Angreifer
+job-role-limited = Limitierte Jobs
+job-role-crafter = Handwerker
+job-role-gatherer = Sammler
+
+character-equipment = Ausrüstung von { $name }
+    .button = Ausrüstung
+    .average = Durchschnittliche Gegenstandsstufe: **{ $level }**
+character-gear-item = **{ $item }** (GS { $level })
+    .materia = Materia: { $materia }
+    .dye = Farbe: { $dye }
+
+gear-slot-main-hand = Haupthand
+gear-slot-off-hand = Nebenhand
+gear-slot-head = Kopf
+gear-slot-body = Rumpf
+gear-slot-hands = Hände
+gear-slot-legs = Beine
+gear-slot-feet = Füße
+gear-slot-earrings = Ohrringe
+gear-slot-necklace = Halskette
+gear-slot-bracelets = Armreif
+gear-slot-ring = Ring
+gear-slot-soul-crystal = Seelenkristall
+
+character-collection-summary = Sammlung von { $name }
+    .points = Errungenschaftspunkte
+    .recent = Neueste Errungenschaften
+    .private = Die Errungenschaften dieses Charakters sind nicht öffentlich.
+character-collection-count = { $owned } / { $total } ({ $percent } %)
+character-collection-list =
+    .mounts = Reittiere von { $name }
+    .minions = Begleiter von { $name }
+    .achievements = Errungenschaften von { $name }
+character-comparison = Charaktervergleich
+    .item-level = Durchschnittliche Gegenstandsstufe
+character-progress = Fortschritt von { $name }
+    .description = { $count } Momentaufnahmen seit { $since }.
+    .empty = Kotonya hat diesen Charakter noch nicht erfasst. Verknüpfte Charaktere werden alle 12 Stunden erfasst, schau später noch einmal vorbei!
+    .item-level = Gegenstandsstufe
+    .total-levels = Summe der Klassen- und Jobstufen
+
+## free company
+
+free-company-not-found = Deine Freie Gesellschaft wurde nicht gefunden!
+    .id = Kotonya konnte keine Freie Gesellschaft mit der angegebenen ID finden, nya!
+    .name = Kotonya konnte keine Freie Gesellschaft mit dem angegebenen Namen finden, nya!
+free-company-not-fetched = Deine Freie Gesellschaft konnte nicht abgerufen werden!
+    .no-free-company = Dein verknüpfter Charakter ist derzeit in keiner Freien Gesellschaft!
+    .not-linked = Mit deinem Discord-Konto ist kein Charakter verknüpft. Bitte verknüpfe deinen Charakter mit `/link character <Name/ID>`!
+free-company-pick = Wähle die Freie Gesellschaft
+free-company-members = Wähle ein Mitglied aus
+    .linked = { $rank } · 🔗 auf diesem Server verknüpft
+free-company-no-members = Keine Mitglieder gefunden!
+    .description = Diese Freie Gesellschaft hat keine öffentlichen Mitglieder.
+    .rank = Diese Freie Gesellschaft hat keine Mitglieder mit dem Rang `{ $rank }`.
+free-company-server-unbound = Dieser Server hat keine Freie Gesellschaft!
+    .description = Serveradmins können eine mit `/freecompany bind` verknüpfen.
+free-company-bound = Freie Gesellschaft verknüpft!
+    .description = { $name } gehört jetzt zu diesem Server. Vergib ihren Mitgliedern mit `/roles free-company` eine Rolle.
+free-company-unbound-successful = Freie Gesellschaft getrennt!
+    .description = { $name } gehört nicht mehr zu diesem Server.
+free-company-not-bound = Freie Gesellschaft nicht verknüpft!
+    .description = `{ $name }` ist nicht mit diesem Server verknüpft.
+free-company-feed-updated = Mitglieder-Feed aktualisiert!
+    .channel = Kotonya postet Beitritte, Austritte, Rang- und Namensänderungen der Freien Gesellschaften dieses Servers in { $channel }. Änderungen erscheinen innerhalb weniger Stunden.
+    .disabled = Kotonya postet auf diesem Server keine Mitgliederänderungen mehr.
+free-company-feed = Mitgliederänderungen von { $name }
+    .joined = 📥 **{ $name }** ist als { $rank } beigetreten.
+    .left = 📤 **{ $name }** ist ausgetreten.
+    .rank = 🎖️ **{ $name }** ist von { $from } zu { $to } gewechselt.
+    .name = ✏️ **{ $from }** heißt jetzt **{ $to }**.
+    .more = …und { $count } weitere.
+free-company-lodestone-id = Lodestone-ID: `{ $id }`
+free-company-formed = Gegründet
+free-company-grand-company = Staatliche Gesellschaft
+free-company-server = Welt
+free-company-active-members = Aktive Mitglieder
+
+## privacy
+
+forget-me-confirm = Alle deine Daten löschen?
+    .description = Dadurch werden alle verknüpften Charaktere, ihre Verifizierung, ihr Fortschrittsverlauf und deine Spracheinstellung aus Kotonya entfernt. Das kann nicht rückgängig gemacht werden!
+    .confirm = Alles löschen
+    .cancel = Abbrechen
+forget-me-done = Deine Daten wurden gelöscht!
+    .description = Kotonya hat alles über dich vergessen, nya.
+forget-me-cancelled = Es wurde nichts gelöscht!
+    .description = Deine Daten sind weiterhin mit deinem Discord-Konto verknüpft.
+
+privacy-updated = Privatsphäre aktualisiert!
+    .hidden = Andere Mitglieder können deine Charaktere nicht mehr nachschlagen.
+    .visible = Andere Mitglieder können deine Charaktere wieder nachschlagen.
+
+## language
+
+language-name =
+    .en = Englisch
+    .ja = Japanisch
+    .de = Deutsch
+    .fr = Französisch
+language-updated = Sprache aktualisiert!
+    .user = Kotonya zeigt dir Spieldaten ab jetzt auf { $language } an, nya!
+    .server = Kotonya zeigt Spieldaten auf diesem Server ab jetzt auf { $language } an, nya!
+
+## leaderboard
+
+leaderboard-title = Rangliste
+    .achievement-points = Rangliste der Errungenschaftspunkte
+    .mounts = Rangliste der Reittiere
+    .minions = Rangliste der Begleiter
+    .total-levels = Rangliste der Jobstufen
+    .job = Rangliste: { $job }
+leaderboard-unknown-job = Unbekannter Job!
+    .description = Wähle den Job aus den Vorschlägen der Option `job`.
+leaderboard-empty = Noch niemand in der Rangliste!
+    .description = Verifizierte Hauptcharaktere erscheinen in Ranglisten, sobald Kotonya sie erfasst hat, was alle 12 Stunden geschieht.
+
+## announcements
+
+announcements-updated = Ankündigungen aktualisiert!
+    .channel = Kotonya kündigt Stufenaufstiege und Meilensteine der Mitglieder in { $channel } an.
+    .disabled = Kotonya kündigt auf diesem Server keine Fortschritte mehr an.
+history-announcement = { $name } hat Fortschritte gemacht!
+    .level-up = { $job } hat Stufe { $level } erreicht.
+    .level-cap = { $job } hat die Höchststufe { $level } erreicht! 🎉
+    .achievement-points = { $points } Errungenschaftspunkte überschritten! 🏆
+
+## nicknames
+
+nicknames-updated = Spitznamen aktualisiert!
+    .enabled = Kotonya benennt Mitglieder jetzt nach ihrem verifizierten Hauptcharakter, sofern sie ihre Charaktere nicht verborgen haben. Mit `/nicknames sync` werden alle sofort umbenannt.
+    .disabled = Kotonya benennt auf diesem Server keine Mitglieder mehr um.
+nicknames-disabled = Die Spitznamen-Synchronisierung ist aus!
+    .description = Schalte sie zuerst mit `/nicknames enable` ein.
+nicknames-synced = Spitznamen synchronisiert!
+    .description = Alle verknüpften Mitglieder wurden umbenannt.
+    .forbidden = { $count ->
+        [one] Kotonya konnte 1 Mitglied nicht umbenennen. Bots dürfen weder den Serverbesitzer noch Mitglieder mit einer höheren Rolle als Kotonya umbenennen.
+       *[other] Kotonya konnte { $count } Mitglieder nicht umbenennen. Bots dürfen weder den Serverbesitzer noch Mitglieder mit einer höheren Rolle als Kotonya umbenennen.
+    }
+    .members = Mitglieder
+
+## roles
+
+roles-updated = Rollen aktualisiert!
+    .set = Mitglieder, auf die `{ $mapping }` zutrifft, erhalten jetzt { $role }.
+    .removed = Kotonya vergibt für `{ $mapping }` keine Rolle mehr. Mitglieder, die sie bereits haben, behalten sie.
+roles-unmapped = `{ $mapping }` ist keiner Rolle zugeordnet!
+roles-mapping = Rollen
+    .empty = Auf diesem Server sind noch keine Rollen zugeordnet.
+roles-synced = Rollen synchronisiert!
+    .description = { $count ->
+        [one] 1 Mitglied synchronisiert.
+       *[other] { $count } Mitglieder synchronisiert.
+    }
+
+## search
+
+search-not-found = { $kind } nicht gefunden!
+    .description = Kotonya konnte keine { $kinds } mit dem angegebenen Namen finden, nya!
+search-nothing-found = Nichts gefunden!
+    .description = Kotonya konnte nichts mit dem angegebenen Namen finden, nya!
+search-pick = Wähle ein Ergebnis, um Details anzuzeigen
+search-filter = Nach Inhaltstyp filtern
+search-filter-all = Alles
+search-back = Zurück zu den Ergebnissen
+search-page = Seite { $page } von { $pages }
+
+search-kind-achievement = Errungenschaft
+    .plural = Errungenschaften
+search-kind-action = Aktion
+    .plural = Aktionen
+search-kind-emote = Emote
+    .plural = Emotes
+search-kind-enemy = Gegner
+    .plural = Gegner
+search-kind-fate = FATE
+    .plural = FATEs
+search-kind-instance_content = Instanz
+    .plural = Instanzen
+search-kind-item = Gegenstand
+    .plural = Gegenstände
+search-kind-leve = Freibrief
+    .plural = Freibriefe
+search-kind-minion = Begleiter
+    .plural = Begleiter
+search-kind-mount = Reittier
+    .plural = Reittiere
+search-kind-npc = NPC
+    .plural = NPCs
+search-kind-place = Ort
+    .plural = Orte
+search-kind-quest = Auftrag
+    .plural = Aufträge
+search-kind-recipe = Rezept
+    .plural = Rezepte
+search-kind-status = Status
+    .plural = Status
+search-kind-title = Titel
+    .plural = Titel
+search-kind-weather = Wetter
+    .plural = Wetter
+
+search-field-points = Punkte
+search-field-category = Kategorie
+search-field-job = Job
+search-field-jobs = Jobs
+search-field-level = Stufe
+search-field-max-level = Maximale Stufe
+search-field-item-level = Gegenstandsstufe
+search-field-equip-level = Anlegestufe
+search-field-range = Reichweite
+search-field-radius = Radius
+search-field-potency = Wirkung
+search-field-cast = Zauberzeit
+search-field-recast = Wiederaufladezeit
+search-field-command = Befehl
+search-field-type = Typ
+search-field-time-limit = Zeitlimit (Min.)
+search-field-issued-at = Ausgestellt in
+search-field-gil-reward = Gil-Belohnung
+search-field-behavior = Verhalten
+search-field-title = Titel
+search-field-genre = Kategorie
+search-field-location = Ort
+search-field-result = Ergebnis
+search-field-amount = Menge
+search-field-ingredients = Zutaten
+search-field-max-stacks = Maximale Stapel
+search-field-permanent = Dauerhaft
+search-field-feminine = Weiblich
+search-field-prefix = Präfix
+search-field-stats = Werte
+search-field-sources = Quellen
+search-yes = Ja
+search-no = Nein
+
+search-source-crafting = Handwerk
+search-source-gil-shops = Gil-Händler
+search-source-exchange-shops = Tauschhändler
+search-source-grand-company = Staatliche Gesellschaft
+search-source-gathering = Sammeln
+search-source-retainer-ventures = Gehilfen-Unternehmungen
+search-source-quest-rewards = Auftragsbelohnungen
+
+## command descriptions
+
+ping = Ein Ping-Befehl.
+link-character = Verknüpfe deinen Charakter mit Kotonya.
+    .input = Dein Charaktername oder deine Lodestone-ID
+link-verify = Verifiziert deine ausstehenden Verknüpfungen mit den Codes in den Lodestone-Profiltexten.
+link-list = Listet deine verknüpften Charaktere auf.
+link-main = Wählt den Charakter, den `self`-Befehle standardmäßig anzeigen.
+    .character = Der verknüpfte Charakter, der dein Hauptcharakter werden soll
+unlink = Hebt die Verknüpfung eines deiner Charaktere auf.
+    .character = Der verknüpfte Charakter, dessen Verknüpfung aufgehoben werden soll
+forget-me = Löscht alle Daten, die Kotonya über dich speichert.
+view_character = FFXIV-Charakter ansehen
+privacy = Verbirgt deine verknüpften Charaktere vor anderen Mitgliedern.
+    .hidden = Ob deine Charaktere vor anderen Mitgliedern verborgen werden
+character-self = Zeigt deinen verknüpften Charakter an.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters angezeigt wird
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-name = Sucht einen Charakter anhand von Name und Welt.
+    .name = Der Name des Charakters
+    .world = Die Welt, das Datenzentrum oder die Region des Charakters
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-id = Sucht einen Charakter anhand seiner Lodestone-ID.
+    .id = Die Lodestone-ID des Charakters
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-user = Zeigt den verknüpften Charakter eines anderen Mitglieds an.
+    .member = Das Mitglied, dessen Charakter angezeigt werden soll
+character-jobs = Zeigt die Klassen- und Jobstufen deines verknüpften Charakters an.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters angezeigt wird
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-gear = Zeigt die angelegte Ausrüstung deines verknüpften Charakters an.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters angezeigt wird
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-collection = Zeigt Reittiere, Begleiter und Errungenschaften deines verknüpften Charakters an.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters angezeigt wird
+    .list = Eine Sammlung, die vollständig aufgelistet werden soll
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-compare = Vergleicht zwei Charaktere nebeneinander.
+    .first = Ein Charaktername, eine Lodestone-ID oder die Erwähnung eines verknüpften Nutzers
+    .second = Ein Charaktername, eine Lodestone-ID oder die Erwähnung eines verknüpften Nutzers
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+character-history = Zeigt den Fortschritt deines verknüpften Charakters im Zeitverlauf.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters angezeigt wird
+freecompany-self = Zeigt die Freie Gesellschaft deines verknüpften Charakters an.
+    .alt = Ein verknüpfter Zweitcharakter, der statt deines Hauptcharakters verwendet wird
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+freecompany-name = Sucht eine Freie Gesellschaft anhand von Name und Welt.
+    .name = Der Name der Freien Gesellschaft
+    .world = Die Welt, das Datenzentrum oder die Region der Freien Gesellschaft
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+freecompany-id = Sucht eine Freie Gesellschaft anhand ihrer Lodestone-ID.
+    .id = Die Lodestone-ID der Freien Gesellschaft
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+freecompany-members = Listet die Mitglieder einer Freien Gesellschaft auf und öffnet ihre Charaktere.
+    .id = Die Lodestone-ID einer anderen Freien Gesellschaft
+    .rank = Nur Mitglieder dieses Rangs auflisten
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+freecompany-server = Zeigt die Freie Gesellschaft dieses Servers.
+    .language = Die Sprache, in der Spieldaten angezeigt werden
+freecompany-bind = Verknüpft eine Freie Gesellschaft mit diesem Server.
+    .id = Die Lodestone-ID der Freien Gesellschaft
+freecompany-unbind = Trennt eine Freie Gesellschaft von diesem Server.
+    .free_company = Die verknüpfte Freie Gesellschaft
+freecompany-feed = Postet Beitritte, Austritte, Rang- und Namensänderungen der Freien Gesellschaften.
+    .channel = Der Kanal für die Beiträge; weglassen, um sie zu beenden
+announcements-channel = Kündigt Stufenaufstiege und Meilensteine der Mitglieder in einem Kanal an.
+    .channel = Der Kanal für Ankündigungen
+announcements-disable = Beendet die Fortschrittsankündigungen auf diesem Server.
+leaderboard = Erstellt eine Rangliste der verifizierten Hauptcharaktere dieses Servers.
+    .ranking = Wonach die Charaktere geordnet werden
+    .job = Der Job, nach dessen Stufe geordnet wird
+language-user = Legt die Sprache fest, in der Kotonya dir Spieldaten anzeigt.
+    .language = Deine bevorzugte Sprache
+language-server = Legt die Sprache fest, in der Kotonya auf diesem Server Spieldaten anzeigt.
+    .language = Die bevorzugte Sprache des Servers
+nicknames-enable = Benennt Mitglieder dieses Servers nach ihrem verknüpften Hauptcharakter.
+    .format = Wie Spitznamen geschrieben werden
+nicknames-disable = Beendet das Umbenennen von Mitgliedern auf diesem Server.
+nicknames-sync = Benennt jetzt alle verknüpften Mitglieder dieses Servers um.
+roles-verified = Vergibt eine Rolle an Mitglieder, die ihren Hauptcharakter verifiziert haben.
+    .role = Die zu vergebende Rolle
+roles-world = Vergibt eine Rolle nach der Welt, dem Datenzentrum oder der Region des Hauptcharakters.
+    .world = Eine Welt, ein Datenzentrum oder eine Region
+    .role = Die zu vergebende Rolle
+roles-grand-company = Vergibt eine Rolle an Mitglieder, deren Hauptcharakter einer Staatlichen Gesellschaft angehört.
+    .company = Die Staatliche Gesellschaft
+    .role = Die zu vergebende Rolle
+roles-combat-role = Vergibt eine Rolle an Mitglieder, deren aktiver Job ein Verteidiger, Heiler oder DD ist.
+    .kind = Die Aufgabe des Jobs in der Gruppe
+    .role = Die zu vergebende Rolle
+roles-free-company = Vergibt eine Rolle an Mitglieder der Freien Gesellschaften dieses Servers.
+    .role = Die zu vergebende Rolle
+roles-remove = Beendet die Vergabe einer zugeordneten Rolle.
+    .mapping = Die zu entfernende Zuordnung
+roles-list = Listet die Rollen auf, die Kotonya auf diesem Server vergibt.
+roles-sync = Aktualisiert jetzt die Rollen aller verknüpften Mitglieder dieses Servers.
+search-all = Durchsucht alle Inhaltstypen des Spiels.
+    .query = Wonach gesucht werden soll
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-achievement = Sucht nach einer Errungenschaft.
+    .name = Der Name der Errungenschaft
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-action = Sucht nach einer Aktion.
+    .name = Der Name der Aktion
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-emote = Sucht nach einem Emote.
+    .name = Der Name des Emotes
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-enemy = Sucht nach einem Gegner.
+    .name = Der Name des Gegners
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-fate = Sucht nach einem FATE.
+    .name = Der Name des FATEs
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-instance_content = Sucht nach einer Instanz.
+    .name = Der Name der Instanz
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-item = Sucht nach einem Gegenstand.
+    .name = Der Name des Gegenstands
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-leve = Sucht nach einem Freibrief.
+    .name = Der Name des Freibriefs
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-minion = Sucht nach einem Begleiter.
+    .name = Der Name des Begleiters
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-mount = Sucht nach einem Reittier.
+    .name = Der Name des Reittiers
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-npc = Sucht nach einem NPC.
+    .name = Der Name des NPCs
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-place = Sucht nach einem Ort.
+    .name = Der Name des Ortes
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-quest = Sucht nach einem Auftrag.
+    .name = Der Name des Auftrags
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-recipe = Sucht nach einem Rezept.
+    .name = Der Name des Rezepts
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-status = Sucht nach einem Status.
+    .name = Der Name des Status
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-title = Sucht nach einem Titel.
+    .name = Der Name des Titels
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
+search-weather = Sucht nach einem Wetter.
+    .name = Der Name des Wetters
+    .language = Die Sprache, in der Ergebnisse angezeigt werden
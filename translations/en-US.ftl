@@ -0,0 +1,299 @@
+pong = pong!
+xivapi-error = xivapi error!
+invalid-world = invalid world!
+invalid-id = invalid ID!
+    .description = `{ $id }` isn't a Lodestone ID. Lodestone IDs are made of digits only, nya!
+
+## character
+
+character-not-found = couldn't find your character!
+    .id = Kotonya couldn't find a character with the given ID, nya!
+    .name = Kotonya couldn't find a character with the given name, nya!
+character-not-linked = couldn't fetch your character!
+    .description = you don't have a character linked to your Discord account. please use `/link character <name/id>` to link your character!
+    .alt = you don't have a character named `{ $alt }` linked. use `/link list` to see your linked characters!
+    .user = { $user } doesn't have a character linked to their Discord account.
+character-lodestone-id = Lodestone ID: `{ $id }`
+character-information = information
+character-city-state = city-state
+character-nameday = nameday
+character-world = world: { $world }
+character-free-company = free company
+    .button = free company
+character-pick = pick your character
+character-unverified = ⚠️ this link hasn't been verified yet. use `/link verify` once the code is in your Lodestone bio.
+character-hidden = this character is hidden!
+    .description = { $user } has chosen not to share their character with other members.
+link-successful = link successful!
+    .description = successfully linked `{ $user }` with `{ $character }`!
+link-verification = verify your character
+    .description = put `{ $code }` anywhere in your Lodestone bio, then use `/link verify` within the hour. you can remove it again once you're verified!
+link-not-pending = nothing to verify!
+    .description = you don't have a pending link. please use `/link character <name/id>` first!
+link-verify-failed = verification failed!
+    .description = `{ $character }`: Kotonya couldn't find `{ $code }` in its Lodestone bio, nya!
+    .retry = the Lodestone can take a few minutes to update, so please try again shortly.
+link-verified = link verified!
+    .description = `{ $character }` is now verified as yours!
+linked-characters = your linked characters
+    .footer = ⭐ main · ⚠️ unverified
+link-main-updated = main updated!
+    .description = `{ $character }` is now your main character!
+unlink-successful = unlink successful!
+    .description = `{ $character }` is no longer linked to your Discord account.
+
+character-back = back to character
+character-job-levels = { $name }'s jobs
+    .button = jobs
+character-job = **{ $job }** Lv. { $level } ({ $progress }%)
+    .max = **{ $job }** Lv. { $level }
+
+job-role-tank = tank
+job-role-healer = healer
+job-role-melee = melee DPS
+job-role-ranged = physical ranged DPS
+job-role-caster = magical ranged DPS
+job-role-limited = limited
+job-role-crafter = disciples of the hand
+job-role-gatherer = disciples of the land
+
+character-equipment = { $name }'s gear
+    .button = gear
+    .average = average item level: **{ $level }**
+character-gear-item = **{ $item }** (i{ $level })
+    .materia = materia: { $materia }
+    .dye = dye: { $dye }
+
+gear-slot-main-hand = main hand
+gear-slot-off-hand = off hand
+gear-slot-head = head
+gear-slot-body = body
+gear-slot-hands = hands
+gear-slot-legs = legs
+gear-slot-feet = feet
+gear-slot-earrings = earrings
+gear-slot-necklace = necklace
+gear-slot-bracelets = bracelets
+gear-slot-ring = ring
+gear-slot-soul-crystal = soul crystal
+
+character-collection-summary = { $name }'s collection
+    .points = achievement points
+    .recent = recent achievements
+    .private = this character's achievements aren't public.
+character-collection-count = { $owned } / { $total } ({ $percent }%)
+character-collection-list =
+    .mounts = { $name }'s mounts
+    .minions = { $name }'s minions
+    .achievements = { $name }'s achievements
+character-comparison = character comparison
+    .item-level = average item level
+character-progress = { $name }'s progress
+    .description = { $count } snapshots since { $since }.
+    .empty = Kotonya hasn't snapshotted this character yet. linked characters are snapshotted every 12 hours, so check back later, nya!
+    .item-level = item level
+    .total-levels = total class and job levels
+
+## free company
+
+free-company-not-found = couldn't find your free company!
+    .id = Kotonya couldn't find the free company with the specified ID, nya!
+    .name = Kotonya couldn't find the free company with the specified name, nya!
+free-company-not-fetched = couldn't fetch your free company!
+    .no-free-company = your linked character is currently not in a free company!
+    .not-linked = you don't have a character linked to your Discord account. please use `/link character <name/id>` to link your character!
+free-company-pick = pick the free company
+free-company-members = pick a member to view
+    .linked = { $rank } · 🔗 linked in this server
+free-company-no-members = no members found!
+    .description = this free company has no public members.
+    .rank = this free company has no members with the rank `{ $rank }`.
+free-company-server-unbound = this server has no free company!
+    .description = server admins can bind one with `/freecompany bind`.
+free-company-bound = free company bound!
+    .description = { $name } now belongs to this server. give its members a role with `/roles free-company`.
+free-company-unbound-successful = free company unbound!
+    .description = { $name } no longer belongs to this server.
+free-company-not-bound = free company not bound!
+    .description = `{ $name }` isn't bound to this server.
+free-company-feed-updated = membership feed updated!
+    .channel = Kotonya will post joins, leaves, rank and name changes of this server's free companies in { $channel }. changes show up within a few hours.
+    .disabled = Kotonya will no longer post membership changes in this server.
+free-company-feed = { $name } roster changes
+    .joined = 📥 **{ $name }** joined as { $rank }.
+    .left = 📤 **{ $name }** left.
+    .rank = 🎖️ **{ $name }** went from { $from } to { $to }.
+    .name = ✏️ **{ $from }** is now **{ $to }**.
+    .more = …and { $count } more.
+free-company-lodestone-id = Lodestone ID: `{ $id }`
+free-company-formed = formed
+free-company-grand-company = grand company
+free-company-server = server
+free-company-active-members = active member count
+
+## privacy
+
+forget-me-confirm = delete all your data?
+    .description = this removes every character you've linked, their verification, their progress history and your language preference from Kotonya. this can't be undone!
+    .confirm = delete everything
+    .cancel = cancel
+forget-me-done = your data has been deleted!
+    .description = Kotonya has forgotten everything about you, nya.
+forget-me-cancelled = nothing was deleted!
+    .description = your data is still linked to your Discord account.
+
+privacy-updated = privacy updated!
+    .hidden = other members can no longer look up your characters.
+    .visible = other members can look up your characters again.
+
+## language
+
+language-name =
+    .en = English
+    .ja = Japanese
+    .de = German
+    .fr = French
+language-updated = language updated!
+    .user = Kotonya will now show you game data in { $language }, nya!
+    .server = Kotonya will now show game data in { $language } on this server, nya!
+
+## leaderboard
+
+leaderboard-title = leaderboard
+    .achievement-points = achievement points leaderboard
+    .mounts = mounts leaderboard
+    .minions = minions leaderboard
+    .total-levels = total job levels leaderboard
+    .job = { $job } leaderboard
+leaderboard-unknown-job = unknown job!
+    .description = pick the job to rank by from the `job` option's suggestions.
+leaderboard-empty = nobody to rank yet!
+    .description = verified main characters show up on leaderboards once Kotonya has snapshotted them, which happens every 12 hours.
+
+## announcements
+
+announcements-updated = announcements updated!
+    .channel = Kotonya will announce members' level-ups and milestones in { $channel }.
+    .disabled = Kotonya will no longer announce members' progress in this server.
+history-announcement = { $name } made progress!
+    .level-up = { $job } reached level { $level }.
+    .level-cap = { $job } reached the level cap of { $level }! 🎉
+    .achievement-points = passed { $points } achievement points! 🏆
+
+## nicknames
+
+nicknames-updated = nicknames updated!
+    .enabled = Kotonya will now rename members after their verified main character, unless they've hidden their characters. use `/nicknames sync` to rename everyone now.
+    .disabled = Kotonya will no longer rename members in this server.
+nicknames-disabled = nickname sync is off!
+    .description = turn it on with `/nicknames enable` first.
+nicknames-synced = nicknames synced!
+    .description = every linked member has been renamed.
+    .forbidden = { $count ->
+        [one] Kotonya couldn't rename 1 member. Discord doesn't let bots rename the server owner or members with a role above Kotonya's.
+       *[other] Kotonya couldn't rename { $count } members. Discord doesn't let bots rename the server owner or members with a role above Kotonya's.
+    }
+    .members = members
+
+## roles
+
+roles-updated = roles updated!
+    .set = members who qualify for `{ $mapping }` will now get { $role }.
+    .removed = Kotonya will no longer give a role for `{ $mapping }`. members who already have it keep it.
+roles-unmapped = `{ $mapping }` isn't mapped to a role!
+roles-mapping = roles
+    .empty = no roles are mapped in this server yet.
+roles-synced = roles synced!
+    .description = { $count ->
+        [one] synced 1 member.
+       *[other] synced { $count } members.
+    }
+
+## search
+
+search-not-found = { $kind } not found!
+    .description = Kotonya couldn't find any { $kinds } with the specified name, nya!
+search-nothing-found = nothing found!
+    .description = Kotonya couldn't find anything with the specified name, nya!
+search-pick = pick a result to view its details
+search-filter = filter by content type
+search-filter-all = everything
+search-back = back to results
+search-page = page { $page } of { $pages }
+
+search-kind-achievement = achievement
+    .plural = achievements
+search-kind-action = action
+    .plural = actions
+search-kind-emote = emote
+    .plural = emotes
+search-kind-enemy = enemy
+    .plural = enemies
+search-kind-fate = fate
+    .plural = FATEs
+search-kind-instance_content = instanced content
+    .plural = instanced content
+search-kind-item = item
+    .plural = items
+search-kind-leve = leve
+    .plural = leve
+search-kind-minion = minion
+    .plural = minions
+search-kind-mount = mount
+    .plural = mounts
+search-kind-npc = NPC
+    .plural = NPCs
+search-kind-place = place
+    .plural = places
+search-kind-quest = quest
+    .plural = quests
+search-kind-recipe = recipe
+    .plural = recipes
+search-kind-status = status
+    .plural = statuses
+search-kind-title = title
+    .plural = titles
+search-kind-weather = weather
+    .plural = weathers
+
+search-field-points = points
+search-field-category = category
+search-field-job = job
+search-field-jobs = jobs
+search-field-level = level
+search-field-max-level = max level
+search-field-item-level = item level
+search-field-equip-level = equip level
+search-field-range = range
+search-field-radius = radius
+search-field-potency = potency
+search-field-cast = cast
+search-field-recast = recast
+search-field-command = command
+search-field-type = type
+search-field-time-limit = time limit (min)
+search-field-issued-at = issued at
+search-field-gil-reward = gil reward
+search-field-behavior = behavior
+search-field-title = title
+search-field-genre = genre
+search-field-location = location
+search-field-result = result
+search-field-amount = amount
+search-field-ingredients = ingredients
+search-field-max-stacks = max stacks
+search-field-permanent = permanent
+search-field-feminine = feminine
+search-field-prefix = prefix
+search-field-stats = stats
+search-field-sources = sources
+search-yes = yes
+search-no = no
+
+search-source-crafting = crafting
+search-source-gil-shops = gil shops
+search-source-exchange-shops = exchange shops
+search-source-grand-company = grand company
+search-source-gathering = gathering
+search-source-retainer-ventures = retainer ventures
+search-source-quest-rewards = quest rewards
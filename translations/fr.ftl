@@ -0,0 +1,450 @@
+pong = Pong !
+xivapi-error = Erreur XIVAPI !
+invalid-world = Monde invalide !
+invalid-id = ID invalide !
+    .description = `{ $id }` n'est pas un ID Lodestone. Les ID Lodestone ne contiennent que des chiffres.
+
+## character
+
+character-not-found = Impossible de trouver ton personnage !
+    .id = Kotonya n'a trouvé aucun personnage avec cet ID, nya !
+    .name = Kotonya n'a trouvé aucun personnage avec ce nom, nya !
+character-not-linked = Impossible de récupérer ton personnage !
+    .description = Aucun personnage n'est lié à ton compte Discord. Utilise `/link character <nom/ID>` pour lier ton personnage !
+    .alt = Aucun personnage nommé `{ $alt }` n'est lié. Utilise `/link list` pour voir tes personnages liés !
+    .user = { $user } n'a lié aucun personnage à son compte Discord.
+character-lodestone-id = ID Lodestone : `{ $id }`
+character-information = informations
+character-city-state = cité-État
+character-nameday = date de naissance
+character-world = monde : { $world }
+character-free-company = Compagnie libre
+    .button = Compagnie libre
+character-pick = Choisis ton personnage
+character-unverified = ⚠️ Cette liaison n'a pas encore été vérifiée. Utilise `/link verify` une fois le code dans ta présentation Lodestone.
+character-hidden = Ce personnage est masqué !
+    .description = { $user } a choisi de ne pas partager son personnage avec les autres membres.
+link-successful = Liaison réussie !
+    .description = `{ $user }` a bien été lié à `{ $character }` !
+link-verification = Vérifie ton personnage
+    .description = Ajoute `{ $code }` n'importe où dans ta présentation Lodestone, puis utilise `/link verify` dans l'heure. Tu pourras le retirer une fois vérifié !
+link-not-pending = Rien à vérifier !
+    .description = Tu n'as aucune liaison en attente. Utilise d'abord `/link character <nom/ID>` !
+link-verify-failed = Échec de la vérification !
+    .description = `{ $character }` : Kotonya n'a pas trouvé `{ $code }` dans sa présentation Lodestone, nya !
+    .retry = Le Lodestone peut mettre quelques minutes à se mettre à jour, réessaie dans un instant.
+link-verified = Liaison vérifiée !
+    .description = `{ $character }` est maintenant vérifié comme étant le tien !
+linked-characters = Tes personnages liés
+    .footer = ⭐ principal · ⚠️ non vérifié
+link-main-updated = Personnage principal mis à jour !
+    .description = `{ $character }` est maintenant ton personnage principal !
+unlink-successful = Liaison supprimée !
+    .description = `{ $character }` n'est plus lié à ton compte Discord.
+
+character-back = Retour au personnage
+character-job-levels = Jobs de { $name }
+    .button = Jobs
+character-job = **{ $job }** niv. { $level } ({ $progress } %)
+    .max = **{ $job }** niv. { $level }
+
+job-role-tank = Tanks
+job-role-healer = Soigneurs
+job-role-melee = DPS de mêlée
+job-role-ranged = DPS physiques à distance
+job-role-caster = DPS magiques à distance
+job-role-limited = Jobs limités
+job-role-crafter = Disciples de la main
+job-role-gatherer = Disciples de la terre
+
+character-equipment = Équipement de { $name }
+    .button = Équipement
+    .average = Niveau d'objet moyen : **{ $level }**
+character-gear-item = **{ $item }** (niv. { $level })
+    .materia = Matérias : { $materia }
+    .dye = Teinture : { $dye }
+
+gear-slot-main-hand = Arme principale
+gear-slot-off-hand = Arme secondaire
+gear-slot-head = Tête
+gear-slot-body = Torse
+gear-slot-hands = Mains
+gear-slot-legs = Jambes
+gear-slot-feet = Pieds
+gear-slot-earrings = Oreilles
+gear-slot-necklace = Cou
+gear-slot-bracelets = Poignets
+gear-slot-ring = Bague
+gear-slot-soul-crystal = Cristal de l'âme
+
+character-collection-summary = Collection de { $name }
+    .points = Points de hauts faits
+    .recent = Hauts faits récents
+    .private = Les hauts faits de ce personnage ne sont pas publics.
+character-collection-count = { $owned } / { $total } ({ $percent } %)
+character-collection-list =
+    .mounts = Montures de { $name }
+    .minions = Mascottes de { $name }
+    .achievements = Hauts faits de { $name }
+character-comparison = Comparaison de personnages
+    .item-level = Niveau d'objet moyen
+character-progress = Progression de { $name }
+    .description = { $count } relevés depuis le { $since }.
+    .empty = Kotonya n'a pas encore relevé ce personnage. Les personnages liés sont relevés toutes les 12 heures, reviens plus tard !
+    .item-level = niveau d'objet
+    .total-levels = total des niveaux de classes et jobs
+
+## free company
+
+free-company-not-found = Impossible de trouver ta compagnie libre !
+    .id = Kotonya n'a trouvé aucune compagnie libre avec cet ID, nya !
+    .name = Kotonya n'a trouvé aucune compagnie libre avec ce nom, nya !
+free-company-not-fetched = Impossible de récupérer ta compagnie libre !
+    .no-free-company = Ton personnage lié ne fait actuellement partie d'aucune compagnie libre !
+    .not-linked = Aucun personnage n'est lié à ton compte Discord. Utilise `/link character <nom/ID>` pour lier ton personnage !
+free-company-pick = Choisis la compagnie libre
+free-company-members = Choisis un membre à afficher
+    .linked = { $rank } · 🔗 lié sur ce serveur
+free-company-no-members = Aucun membre trouvé !
+    .description = Cette compagnie libre n'a aucun membre public.
+    .rank = Cette compagnie libre n'a aucun membre ayant le rang `{ $rank }`.
+free-company-server-unbound = Ce serveur n'a pas de compagnie libre !
+    .description = Les admins du serveur peuvent en associer une avec `/freecompany bind`.
+free-company-bound = Compagnie libre associée !
+    .description = { $name } appartient désormais à ce serveur. Donne un rôle à ses membres avec `/roles free-company`.
+free-company-unbound-successful = Compagnie libre dissociée !
+    .description = { $name } n'appartient plus à ce serveur.
+free-company-not-bound = Compagnie libre non associée !
+    .description = `{ $name }` n'est pas associée à ce serveur.
+free-company-feed-updated = Flux des membres mis à jour !
+    .channel = Kotonya publiera les arrivées, départs, changements de rang et de nom des compagnies libres de ce serveur dans { $channel }. Les changements apparaissent en quelques heures.
+    .disabled = Kotonya ne publiera plus les changements de membres sur ce serveur.
+free-company-feed = Changements de membres de { $name }
+    .joined = 📥 **{ $name }** a rejoint en tant que { $rank }.
+    .left = 📤 **{ $name }** est parti·e.
+    .rank = 🎖️ **{ $name }** est passé·e de { $from } à { $to }.
+    .name = ✏️ **{ $from }** s'appelle désormais **{ $to }**.
+    .more = …et { $count } de plus.
+free-company-lodestone-id = ID Lodestone : `{ $id }`
+free-company-formed = fondée le
+free-company-grand-company = grande compagnie
+free-company-server = monde
+free-company-active-members = membres actifs
+
+## privacy
+
+forget-me-confirm = Supprimer toutes tes données ?
+    .description = Cela supprime de Kotonya tous tes personnages liés, leur vérification, leur historique de progression et ta préférence de langue. C'est irréversible !
+    .confirm = Tout supprimer
+    .cancel = Annuler
+forget-me-done = Tes données ont été supprimées !
+    .description = Kotonya a tout oublié de toi, nya.
+forget-me-cancelled = Rien n'a été supprimé !
+    .description = Tes données sont toujours liées à ton compte Discord.
+
+privacy-updated = Confidentialité mise à jour !
+    .hidden = Les autres membres ne peuvent plus consulter tes personnages.
+    .visible = Les autres membres peuvent de nouveau consulter tes personnages.
+
+## language
+
+language-name =
+    .en = anglais
+    .ja = japonais
+    .de = allemand
+    .fr = français
+language-updated = Langue mise à jour !
+    .user = Kotonya t'affichera désormais les données du jeu en { $language }, nya !
+    .server = Kotonya affichera désormais les données du jeu en { $language } sur ce serveur, nya !
+
+## leaderboard
+
+leaderboard-title = Classement
+    .achievement-points = Classement des points de haut fait
+    .mounts = Classement des montures
+    .minions = Classement des mascottes
+    .total-levels = Classement des niveaux de jobs
+    .job = Classement : { $job }
+leaderboard-unknown-job = Job inconnu !
+    .description = Choisis le job parmi les suggestions de l'option `job`.
+leaderboard-empty = Personne à classer pour l'instant !
+    .description = Les personnages principaux vérifiés apparaissent dans les classements une fois relevés par Kotonya, ce qui a lieu toutes les 12 heures.
+
+## announcements
+
+announcements-updated = Annonces mises à jour !
+    .channel = Kotonya annoncera les montées de niveau et les étapes des membres dans { $channel }.
+    .disabled = Kotonya n'annoncera plus la progression des membres sur ce serveur.
+history-announcement = { $name } a progressé !
+    .level-up = { $job } a atteint le niveau { $level }.
+    .level-cap = { $job } a atteint le niveau maximum { $level } ! 🎉
+    .achievement-points = a dépassé { $points } points de haut fait ! 🏆
+
+## nicknames
+
+nicknames-updated = Pseudos mis à jour !
+    .enabled = Kotonya renommera désormais les membres d'après leur personnage principal vérifié, sauf s'ils ont masqué leurs personnages. Utilise `/nicknames sync` pour renommer tout le monde maintenant.
+    .disabled = Kotonya ne renommera plus les membres de ce serveur.
+nicknames-disabled = La synchronisation des pseudos est désactivée !
+    .description = Active-la d'abord avec `/nicknames enable`.
+nicknames-synced = Pseudos synchronisés !
+    .description = Tous les membres liés ont été renommés.
+    .forbidden = { $count ->
+        [one] Kotonya n'a pas pu renommer 1 membre. Discord n'autorise pas les bots à renommer le propriétaire du serveur ni les membres ayant un rôle au-dessus de celui de Kotonya.
+       *[other] Kotonya n'a pas pu renommer { $count } membres. Discord n'autorise pas les bots à renommer le propriétaire du serveur ni les membres ayant un rôle au-dessus de celui de Kotonya.
+    }
+    .members = membres
+
+## roles
+
+roles-updated = Rôles mis à jour !
+    .set = Les membres correspondant à `{ $mapping }` recevront désormais { $role }.
+    .removed = Kotonya ne donnera plus de rôle pour `{ $mapping }`. Les membres qui l'ont déjà le gardent.
+roles-unmapped = `{ $mapping }` n'est associé à aucun rôle !
+roles-mapping = Rôles
+    .empty = Aucun rôle n'est encore associé sur ce serveur.
+roles-synced = Rôles synchronisés !
+    .description = { $count ->
+        [one] 1 membre synchronisé.
+       *[other] { $count } membres synchronisés.
+    }
+
+## search
+
+search-not-found = { $kind } introuvable !
+    .description = Kotonya n'a trouvé aucun(e) { $kinds } avec ce nom, nya !
+search-nothing-found = Aucun résultat !
+    .description = Kotonya n'a rien trouvé avec ce nom, nya !
+search-pick = choisis un résultat pour voir ses détails
+search-filter = filtrer par type de contenu
+search-filter-all = tout
+search-back = retour aux résultats
+search-page = page { $page } sur { $pages }
+
+search-kind-achievement = haut fait
+    .plural = hauts faits
+search-kind-action = action
+    .plural = actions
+search-kind-emote = emote
+    .plural = emotes
+search-kind-enemy = ennemi
+    .plural = ennemis
+search-kind-fate = ALÉA
+    .plural = ALÉA
+search-kind-instance_content = contenu instancié
+    .plural = contenus instanciés
+search-kind-item = objet
+    .plural = objets
+search-kind-leve = mandat
+    .plural = mandats
+search-kind-minion = mascotte
+    .plural = mascottes
+search-kind-mount = monture
+    .plural = montures
+search-kind-npc = PNJ
+    .plural = PNJ
+search-kind-place = lieu
+    .plural = lieux
+search-kind-quest = quête
+    .plural = quêtes
+search-kind-recipe = recette
+    .plural = recettes
+search-kind-status = effet
+    .plural = effets
+search-kind-title = titre
+    .plural = titres
+search-kind-weather = météo
+    .plural = météos
+
+search-field-points = points
+search-field-category = catégorie
+search-field-job = job
+search-field-jobs = jobs
+search-field-level = niveau
+search-field-max-level = niveau max
+search-field-item-level = niveau d'objet
+search-field-equip-level = niveau requis
+search-field-range = portée
+search-field-radius = rayon
+search-field-potency = puissance
+search-field-cast = incantation
+search-field-recast = recharge
+search-field-command = commande
+search-field-type = type
+search-field-time-limit = temps limite (min)
+search-field-issued-at = délivré à
+search-field-gil-reward = gils en récompense
+search-field-behavior = comportement
+search-field-title = titre
+search-field-genre = genre
+search-field-location = lieu
+search-field-result = résultat
+search-field-amount = quantité
+search-field-ingredients = ingrédients
+search-field-max-stacks = accumulations max
+search-field-permanent = permanent
+search-field-feminine = féminin
+search-field-prefix = préfixe
+search-field-stats = attributs
+search-field-sources = obtention
+search-yes = oui
+search-no = non
+
+search-source-crafting = artisanat
+search-source-gil-shops = marchands (gils)
+search-source-exchange-shops = marchands d'échange
+search-source-grand-company = grande compagnie
+search-source-gathering = récolte
+search-source-retainer-ventures = tâches de servant
+search-source-quest-rewards = récompenses de quête
+
+## command descriptions
+
+ping = Une commande ping.
+link-character = Lie ton personnage à Kotonya.
+    .input = Le nom ou l'ID Lodestone de ton personnage
+link-verify = Vérifie tes liaisons en attente grâce aux codes dans les présentations Lodestone.
+link-list = Affiche la liste de tes personnages liés.
+link-main = Choisis le personnage affiché par défaut par les commandes `self`.
+    .character = Le personnage lié à définir comme principal
+unlink = Supprime la liaison de l'un de tes personnages.
+    .character = Le personnage lié à délier
+forget-me = Supprime toutes les données que Kotonya conserve sur toi.
+view_character = Voir le personnage FFXIV
+privacy = Masque tes personnages liés aux autres membres.
+    .hidden = Si tes personnages doivent être masqués aux autres membres
+character-self = Affiche ton personnage lié.
+    .alt = Un personnage secondaire lié à afficher à la place du principal
+    .language = La langue d'affichage des données du jeu
+character-name = Cherche un personnage par son nom et son monde.
+    .name = Le nom du personnage
+    .world = Le monde, le centre de données ou la région du personnage
+    .language = La langue d'affichage des données du jeu
+character-id = Cherche un personnage par son ID Lodestone.
+    .id = L'ID Lodestone du personnage
+    .language = La langue d'affichage des données du jeu
+character-user = Affiche le personnage lié d'un autre membre.
+    .member = Le membre dont afficher le personnage
+character-jobs = Affiche les niveaux de classe et de job de ton personnage lié.
+    .alt = Un personnage secondaire lié à afficher à la place du principal
+    .language = La langue d'affichage des données du jeu
+character-gear = Affiche l'équipement porté par ton personnage lié.
+    .alt = Un personnage secondaire lié à afficher à la place du principal
+    .language = La langue d'affichage des données du jeu
+character-collection = Affiche les montures, mascottes et hauts faits de ton personnage lié.
+    .alt = Un personnage secondaire lié à afficher à la place du principal
+    .list = Une collection à afficher en entier
+    .language = La langue d'affichage des données du jeu
+character-compare = Compare deux personnages côte à côte.
+    .first = Un nom de personnage, un ID Lodestone ou la mention d'un utilisateur lié
+    .second = Un nom de personnage, un ID Lodestone ou la mention d'un utilisateur lié
+    .language = La langue d'affichage des données du jeu
+character-history = Affiche la progression de ton personnage lié au fil du temps.
+    .alt = Un personnage secondaire lié à afficher à la place de ton principal
+freecompany-self = Affiche la compagnie libre de ton personnage lié.
+    .alt = Un personnage secondaire lié à utiliser à la place du principal
+    .language = La langue d'affichage des données du jeu
+freecompany-name = Cherche une compagnie libre par son nom et son monde.
+    .name = Le nom de la compagnie libre
+    .world = Le monde, le centre de données ou la région de la compagnie libre
+    .language = La langue d'affichage des données du jeu
+freecompany-id = Cherche une compagnie libre par son ID Lodestone.
+    .id = L'ID Lodestone de la compagnie libre
+    .language = La langue d'affichage des données du jeu
+freecompany-members = Liste les membres d'une compagnie libre et ouvre leurs personnages.
+    .id = L'ID Lodestone d'une autre compagnie libre
+    .rank = Ne lister que les membres de ce rang
+    .language = La langue d'affichage des données du jeu
+freecompany-server = Affiche la compagnie libre de ce serveur.
+    .language = La langue d'affichage des données du jeu
+freecompany-bind = Associe une compagnie libre à ce serveur.
+    .id = L'ID Lodestone de la compagnie libre
+freecompany-unbind = Dissocie une compagnie libre de ce serveur.
+    .free_company = La compagnie libre associée
+freecompany-feed = Publie les arrivées, départs, rangs et noms des membres des compagnies libres.
+    .channel = Le salon où publier ; laisser vide pour arrêter
+announcements-channel = Annonce les montées de niveau et les étapes des membres dans un salon.
+    .channel = Le salon où annoncer
+announcements-disable = Arrête d'annoncer la progression des membres sur ce serveur.
+leaderboard = Classe les personnages principaux vérifiés de ce serveur.
+    .ranking = Le critère du classement
+    .job = Le job à classer, pour un classement par niveau de job
+language-user = Choisis la langue dans laquelle Kotonya t'affiche les données du jeu.
+    .language = Ta langue préférée
+language-server = Choisis la langue dans laquelle Kotonya affiche les données du jeu sur ce serveur.
+    .language = La langue préférée du serveur
+nicknames-enable = Renomme les membres de ce serveur d'après leur personnage principal lié.
+    .format = La façon d'écrire les pseudos
+nicknames-disable = Arrête de renommer les membres de ce serveur.
+nicknames-sync = Renomme maintenant tous les membres liés de ce serveur.
+roles-verified = Donne un rôle aux membres ayant vérifié leur personnage principal.
+    .role = Le rôle à donner
+roles-world = Donne un rôle selon le monde, le centre de données ou la région du personnage principal.
+    .world = Un monde, un centre de données ou une région
+    .role = Le rôle à donner
+roles-grand-company = Donne un rôle aux membres dont le personnage principal appartient à une Grande Compagnie.
+    .company = La Grande Compagnie
+    .role = Le rôle à donner
+roles-combat-role = Donne un rôle aux membres dont le job actif est tank, soigneur ou DPS.
+    .kind = Le rôle du job en équipe
+    .role = Le rôle à donner
+roles-free-company = Donne un rôle aux membres dont le personnage principal est dans une compagnie libre de ce serveur.
+    .role = Le rôle à donner
+roles-remove = Arrête de donner un rôle associé.
+    .mapping = L'association à supprimer
+roles-list = Liste les rôles que Kotonya donne sur ce serveur.
+roles-sync = Met à jour maintenant les rôles de tous les membres liés de ce serveur.
+search-all = Cherche n'importe quel contenu du jeu.
+    .query = Ce qu'il faut chercher
+    .language = La langue d'affichage des résultats
+search-achievement = Cherche un haut fait.
+    .name = Le nom du haut fait
+    .language = La langue d'affichage des résultats
+search-action = Cherche une action.
+    .name = Le nom de l'action
+    .language = La langue d'affichage des résultats
+search-emote = Cherche une emote.
+    .name = Le nom de l'emote
+    .language = La langue d'affichage des résultats
+search-enemy = Cherche un ennemi.
+    .name = Le nom de l'ennemi
+    .language = La langue d'affichage des résultats
+search-fate = Cherche un ALÉA.
+    .name = Le nom de l'ALÉA
+    .language = La langue d'affichage des résultats
+search-instance_content = Cherche un contenu instancié.
+    .name = Le nom du contenu instancié
+    .language = La langue d'affichage des résultats
+search-item = Cherche un objet.
+    .name = Le nom de l'objet
+    .language = La langue d'affichage des résultats
+search-leve = Cherche un mandat.
+    .name = Le nom du mandat
+    .language = La langue d'affichage des résultats
+search-minion = Cherche une mascotte.
+    .name = Le nom de la mascotte
+    .language = La langue d'affichage des résultats
+search-mount = Cherche une monture.
+    .name = Le nom de la monture
+    .language = La langue d'affichage des résultats
+search-npc = Cherche un PNJ.
+    .name = Le nom du PNJ
+    .language = La langue d'affichage des résultats
+search-place = Cherche un lieu.
+    .name = Le nom du lieu
+    .language = La langue d'affichage des résultats
+search-quest = Cherche une quête.
+    .name = Le nom de la quête
+    .language = La langue d'affichage des résultats
+search-recipe = Cherche une recette.
+    .name = Le nom de la recette
+    .language = La langue d'affichage des résultats
+search-status = Cherche un effet.
+    .name = Le nom de l'effet
+    .language = La langue d'affichage des résultats
+search-title = Cherche un titre.
+    .name = Le nom du titre
+    .language = La langue d'affichage des résultats
+search-weather = Cherche une météo.
+    .name = Le nom de la météo
+    .language = La langue d'affichage des résultats
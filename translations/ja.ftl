@@ -0,0 +1,444 @@
+pong = ポン！
+xivapi-error = XIVAPIエラー！
+invalid-world = 無効なワールドです！
+invalid-id = 無効なIDです！
+    .description = `{ $id }` はロドストーンIDではありません。ロドストーンIDは数字のみです。
+
+## character
+
+character-not-found = キャラクターが見つかりませんでした！
+    .id = 指定されたIDのキャラクターが見つからなかったにゃ！
+    .name = 指定された名前のキャラクターが見つからなかったにゃ！
+character-not-linked = キャラクターを取得できませんでした！
+    .description = Discordアカウントにキャラクターが紐付けられていません。`/link character <名前/ID>` でキャラクターを紐付けてください！
+    .alt = `{ $alt }` という名前のキャラクターは紐付けられていません。`/link list` で紐付けたキャラクターを確認できます！
+    .user = { $user } はDiscordアカウントにキャラクターを紐付けていません。
+character-lodestone-id = ロドストーンID: `{ $id }`
+character-information = 情報
+character-city-state = 開始都市
+character-nameday = 誕生日
+character-world = ワールド: { $world }
+character-free-company = フリーカンパニー
+    .button = フリーカンパニー
+character-pick = キャラクターを選んでください
+character-unverified = ⚠️ この紐付けはまだ認証されていません。ロドストーンの自己紹介にコードを記入してから `/link verify` を使ってください。
+character-hidden = このキャラクターは非公開です！
+    .description = { $user } はキャラクターを他のメンバーに公開していません。
+link-successful = 紐付けに成功しました！
+    .description = `{ $user }` を `{ $character }` に紐付けました！
+link-verification = キャラクターの認証
+    .description = ロドストーンの自己紹介に `{ $code }` を記入して、1時間以内に `/link verify` を使ってください。認証後は削除して大丈夫です！
+link-not-pending = 認証するものがありません！
+    .description = 保留中の紐付けがありません。先に `/link character <名前/ID>` を使ってください！
+link-verify-failed = 認証に失敗しました！
+    .description = `{ $character }`：ロドストーンの自己紹介に `{ $code }` が見つかりませんでした、にゃ！
+    .retry = ロドストーンの更新には数分かかることがあるので、少し待ってからもう一度お試しください。
+link-verified = 認証しました！
+    .description = `{ $character }` があなたのキャラクターとして認証されました！
+linked-characters = 紐付けたキャラクター
+    .footer = ⭐ メイン · ⚠️ 未認証
+link-main-updated = メインを変更しました！
+    .description = `{ $character }` がメインキャラクターになりました！
+unlink-successful = 紐付けを解除しました！
+    .description = `{ $character }` とDiscordアカウントの紐付けを解除しました。
+
+character-back = キャラクターに戻る
+character-job-levels = { $name }のジョブ
+    .button = ジョブ
+character-job = **{ $job }** Lv. { $level }（{ $progress }%）
+    .max = **{ $job }** Lv. { $level }
+
+job-role-tank = タンク
+job-role-healer = ヒーラー
+job-role-melee = 近接物理DPS
+job-role-ranged = 遠隔物理DPS
+job-role-caster = 遠隔魔法DPS
+job-role-limited = リミテッドジョブ
+job-role-crafter = クラフター
+job-role-gatherer = ギャザラー
+
+character-equipment = { $name }の装備
+    .button = 装備
+    .average = 平均アイテムレベル：**{ $level }**
+character-gear-item = **{ $item }**（IL{ $level }）
+    .materia = マテリア：{ $materia }
+    .dye = カララント：{ $dye }
+
+gear-slot-main-hand = メインアーム
+gear-slot-off-hand = サブアーム
+gear-slot-head = 頭
+gear-slot-body = 胴
+gear-slot-hands = 手
+gear-slot-legs = 脚
+gear-slot-feet = 足
+gear-slot-earrings = 耳
+gear-slot-necklace = 首
+gear-slot-bracelets = 腕
+gear-slot-ring = 指
+gear-slot-soul-crystal = ソウルクリスタル
+
+character-collection-summary = { $name }のコレクション
+    .points = アチーブメントポイント
+    .recent = 最近のアチーブメント
+    .private = このキャラクターのアチーブメントは公開されていません。
+character-collection-count = { $owned } / { $total }（{ $percent }%）
+character-collection-list =
+    .mounts = { $name }のマウント
+    .minions = { $name }のミニオン
+    .achievements = { $name }のアチーブメント
+character-comparison = キャラクター比較
+    .item-level = 平均アイテムレベル
+character-progress = { $name }の成長記録
+    .description = { $since } から{ $count }回の記録
+    .empty = このキャラクターはまだ記録されていません。紐付けたキャラクターは12時間ごとに記録されるので、後でもう一度確認してください！
+    .item-level = アイテムレベル
+    .total-levels = クラス・ジョブのレベル合計
+
+## free company
+
+free-company-not-found = フリーカンパニーが見つかりませんでした！
+    .id = 指定されたIDのフリーカンパニーが見つからなかったにゃ！
+    .name = 指定された名前のフリーカンパニーが見つからなかったにゃ！
+free-company-not-fetched = フリーカンパニーを取得できませんでした！
+    .no-free-company = 紐付けたキャラクターは現在フリーカンパニーに所属していません！
+    .not-linked = Discordアカウントにキャラクターが紐付けられていません。`/link character <名前/ID>` でキャラクターを紐付けてください！
+free-company-pick = フリーカンパニーを選んでください
+free-company-members = 表示するメンバーを選んでください
+    .linked = { $rank } · 🔗 このサーバーで紐付け済み
+free-company-no-members = メンバーが見つかりませんでした！
+    .description = このフリーカンパニーには公開されているメンバーがいません。
+    .rank = このフリーカンパニーには「{ $rank }」のランクのメンバーがいません。
+free-company-server-unbound = このサーバーにはフリーカンパニーが登録されていません！
+    .description = サーバー管理者は `/freecompany bind` で登録できます。
+free-company-bound = フリーカンパニーを登録しました！
+    .description = { $name }をこのサーバーに登録しました。`/roles free-company` でメンバーにロールを付与できます。
+free-company-unbound-successful = フリーカンパニーの登録を解除しました！
+    .description = { $name }はこのサーバーに登録されていません。
+free-company-not-bound = フリーカンパニーが登録されていません！
+    .description = `{ $name }` はこのサーバーに登録されていません。
+free-company-feed-updated = メンバー通知を更新しました！
+    .channel = このサーバーのフリーカンパニーの加入・脱退・ランク変更・名前変更を { $channel } でお知らせします。変更は数時間以内に反映されます。
+    .disabled = このサーバーでのメンバー変更のお知らせを停止しました。
+free-company-feed = { $name } のメンバー変更
+    .joined = 📥 **{ $name }** が { $rank } として加入しました。
+    .left = 📤 **{ $name }** が脱退しました。
+    .rank = 🎖️ **{ $name }** のランクが { $from } から { $to } に変わりました。
+    .name = ✏️ **{ $from }** は **{ $to }** に名前を変えました。
+    .more = …ほか { $count } 件。
+free-company-lodestone-id = ロドストーンID: `{ $id }`
+free-company-formed = 結成日
+free-company-grand-company = グランドカンパニー
+free-company-server = ワールド
+free-company-active-members = アクティブメンバー数
+
+## privacy
+
+forget-me-confirm = すべてのデータを削除しますか？
+    .description = 紐付けたキャラクター、その認証と成長の記録、言語設定をKotonyaからすべて削除します。元に戻すことはできません！
+    .confirm = すべて削除
+    .cancel = キャンセル
+forget-me-done = データを削除しました！
+    .description = Kotonyaはあなたのことをすべて忘れました、にゃ。
+forget-me-cancelled = 何も削除されませんでした！
+    .description = データはDiscordアカウントに紐付けられたままです。
+
+privacy-updated = プライバシー設定を更新しました！
+    .hidden = 他のメンバーはあなたのキャラクターを検索できなくなりました。
+    .visible = 他のメンバーが再びあなたのキャラクターを検索できるようになりました。
+
+## language
+
+language-name =
+    .en = 英語
+    .ja = 日本語
+    .de = ドイツ語
+    .fr = フランス語
+language-updated = 言語を更新しました！
+    .user = これからは { $language } でゲームデータを表示するにゃ！
+    .server = このサーバーでは { $language } でゲームデータを表示するにゃ！
+
+## leaderboard
+
+leaderboard-title = ランキング
+    .achievement-points = アチーブメントポイントランキング
+    .mounts = マウントランキング
+    .minions = ミニオンランキング
+    .total-levels = ジョブレベル合計ランキング
+    .job = { $job }ランキング
+leaderboard-unknown-job = 不明なジョブです！
+    .description = `job` オプションの候補からランキングするジョブを選んでください。
+leaderboard-empty = まだランキングするメンバーがいません！
+    .description = 認証済みのメインキャラクターは、Kotonyaが記録した後にランキングに表示されます。記録は12時間ごとに行われます。
+
+## announcements
+
+announcements-updated = お知らせ設定を更新しました！
+    .channel = メンバーのレベルアップや達成を { $channel } でお知らせします。
+    .disabled = このサーバーではメンバーの成長をお知らせしなくなりました。
+history-announcement = { $name }が成長しました！
+    .level-up = { $job }がレベル{ $level }になりました。
+    .level-cap = { $job }がレベル上限の{ $level }に到達しました！🎉
+    .achievement-points = アチーブメントポイントが{ $points }を超えました！🏆
+
+## nicknames
+
+nicknames-updated = ニックネーム設定を更新しました！
+    .enabled = 認証済みのメインキャラクター名にメンバーのニックネームを変更します（キャラクターを非公開にしたメンバーを除く）。今すぐ全員を変更するには `/nicknames sync` を使ってください。
+    .disabled = このサーバーではメンバーのニックネームを変更しなくなりました。
+nicknames-disabled = ニックネームの同期がオフです！
+    .description = 先に `/nicknames enable` でオンにしてください。
+nicknames-synced = ニックネームを同期しました！
+    .description = 紐付け済みのメンバー全員のニックネームを変更しました。
+    .forbidden = { $count }人のメンバーのニックネームを変更できませんでした。Discordでは、サーバーオーナーやKotonyaより上のロールを持つメンバーのニックネームをBotが変更できません。
+    .members = メンバー
+
+## roles
+
+roles-updated = ロールを更新しました！
+    .set = `{ $mapping }` に該当するメンバーに { $role } を付与します。
+    .removed = `{ $mapping }` のロールの付与を停止しました。既に付与されたロールは残ります。
+roles-unmapped = `{ $mapping }` にはロールが設定されていません！
+roles-mapping = ロール
+    .empty = このサーバーにはまだロールが設定されていません。
+roles-synced = ロールを同期しました！
+    .description = { $count }人のメンバーを同期しました。
+
+## search
+
+search-not-found = { $kind }が見つかりませんでした！
+    .description = 指定された名前の{ $kinds }が見つからなかったにゃ！
+search-nothing-found = 何も見つかりませんでした！
+    .description = 指定された名前のものは何も見つからなかったにゃ！
+search-pick = 詳細を表示する結果を選択
+search-filter = 種類で絞り込む
+search-filter-all = すべて
+search-back = 検索結果に戻る
+search-page = { $page } / { $pages } ページ
+
+search-kind-achievement = アチーブメント
+    .plural = アチーブメント
+search-kind-action = アクション
+    .plural = アクション
+search-kind-emote = エモート
+    .plural = エモート
+search-kind-enemy = エネミー
+    .plural = エネミー
+search-kind-fate = F.A.T.E.
+    .plural = F.A.T.E.
+search-kind-instance_content = インスタンスコンテンツ
+    .plural = インスタンスコンテンツ
+search-kind-item = アイテム
+    .plural = アイテム
+search-kind-leve = リーヴ
+    .plural = リーヴ
+search-kind-minion = ミニオン
+    .plural = ミニオン
+search-kind-mount = マウント
+    .plural = マウント
+search-kind-npc = NPC
+    .plural = NPC
+search-kind-place = 地名
+    .plural = 地名
+search-kind-quest = クエスト
+    .plural = クエスト
+search-kind-recipe = レシピ
+    .plural = レシピ
+search-kind-status = ステータス
+    .plural = ステータス
+search-kind-title = 称号
+    .plural = 称号
+search-kind-weather = 天候
+    .plural = 天候
+
+search-field-points = ポイント
+search-field-category = カテゴリ
+search-field-job = ジョブ
+search-field-jobs = ジョブ
+search-field-level = レベル
+search-field-max-level = 最大レベル
+search-field-item-level = アイテムレベル
+search-field-equip-level = 装備レベル
+search-field-range = 射程
+search-field-radius = 範囲
+search-field-potency = 威力
+search-field-cast = 詠唱時間
+search-field-recast = リキャスト
+search-field-command = コマンド
+search-field-type = 種類
+search-field-time-limit = 制限時間（分）
+search-field-issued-at = 受注場所
+search-field-gil-reward = 報酬ギル
+search-field-behavior = 性格
+search-field-title = 肩書
+search-field-genre = ジャンル
+search-field-location = 場所
+search-field-result = 完成品
+search-field-amount = 完成個数
+search-field-ingredients = 素材
+search-field-max-stacks = 最大スタック数
+search-field-permanent = 永続
+search-field-feminine = 女性形
+search-field-prefix = 前置
+search-field-stats = ステータス
+search-field-sources = 入手方法
+search-yes = はい
+search-no = いいえ
+
+search-source-crafting = 製作
+search-source-gil-shops = ギルショップ
+search-source-exchange-shops = 交換ショップ
+search-source-grand-company = グランドカンパニー
+search-source-gathering = 採集
+search-source-retainer-ventures = リテイナーベンチャー
+search-source-quest-rewards = クエスト報酬
+
+## command descriptions
+
+ping = pingコマンド。
+link-character = キャラクターをKotonyaに紐付けます。
+    .input = キャラクター名またはロドストーンID
+link-verify = ロドストーンの自己紹介に記入したコードで保留中の紐付けを認証します。
+link-list = 紐付けたキャラクターの一覧を表示します。
+link-main = `self` コマンドで表示するキャラクターを選びます。
+    .character = メインにする紐付け済みのキャラクター
+unlink = キャラクターの紐付けを解除します。
+    .character = 紐付けを解除するキャラクター
+forget-me = Kotonyaに保存されているあなたのデータをすべて削除します。
+view_character = FFXIVキャラクターを見る
+privacy = 紐付けたキャラクターを他のメンバーから非公開にします。
+    .hidden = 他のメンバーからキャラクターを隠すかどうか
+character-self = 紐付けたキャラクターを表示します。
+    .alt = メインの代わりに表示するサブキャラクター
+    .language = ゲームデータを表示する言語
+character-name = 名前とワールドでキャラクターを検索します。
+    .name = キャラクター名
+    .world = キャラクターのワールド、データセンター、または地域
+    .language = ゲームデータを表示する言語
+character-id = ロドストーンIDでキャラクターを検索します。
+    .id = キャラクターのロドストーンID
+    .language = ゲームデータを表示する言語
+character-user = 他のメンバーが紐付けたキャラクターを表示します。
+    .member = キャラクターを表示するメンバー
+character-jobs = 紐付けたキャラクターのクラス・ジョブのレベルを表示します。
+    .alt = メインの代わりに表示するサブキャラクター
+    .language = ゲームデータを表示する言語
+character-gear = 紐付けたキャラクターの装備を表示します。
+    .alt = メインの代わりに表示するサブキャラクター
+    .language = ゲームデータを表示する言語
+character-collection = 紐付けたキャラクターのマウント、ミニオン、アチーブメントを表示します。
+    .alt = メインの代わりに表示するサブキャラクター
+    .list = すべて一覧表示するコレクション
+    .language = ゲームデータを表示する言語
+character-compare = 2人のキャラクターを並べて比較します。
+    .first = キャラクター名、ロドストーンID、または紐付け済みユーザーのメンション
+    .second = キャラクター名、ロドストーンID、または紐付け済みユーザーのメンション
+    .language = ゲームデータを表示する言語
+character-history = 紐付けたキャラクターの成長の推移を表示します。
+    .alt = メインの代わりに表示するサブキャラクター
+freecompany-self = 紐付けたキャラクターのフリーカンパニーを表示します。
+    .alt = メインの代わりに使うサブキャラクター
+    .language = ゲームデータを表示する言語
+freecompany-name = 名前とワールドでフリーカンパニーを検索します。
+    .name = フリーカンパニー名
+    .world = フリーカンパニーのワールド、データセンター、または地域
+    .language = ゲームデータを表示する言語
+freecompany-id = ロドストーンIDでフリーカンパニーを検索します。
+    .id = フリーカンパニーのロドストーンID
+    .language = ゲームデータを表示する言語
+freecompany-members = フリーカンパニーのメンバー一覧を表示し、キャラクターを開きます。
+    .id = 別のフリーカンパニーのロドストーンID
+    .rank = このランクのメンバーだけを表示
+    .language = ゲームデータを表示する言語
+freecompany-server = このサーバーのフリーカンパニーを表示します。
+    .language = ゲームデータを表示する言語
+freecompany-bind = フリーカンパニーをこのサーバーに登録します。
+    .id = フリーカンパニーのロドストーンID
+freecompany-unbind = フリーカンパニーのこのサーバーへの登録を解除します。
+    .free_company = 登録済みのフリーカンパニー
+freecompany-feed = フリーカンパニーの加入・脱退・ランクと名前の変更をチャンネルでお知らせします。
+    .channel = お知らせするチャンネル（省略すると停止）
+announcements-channel = メンバーのレベルアップや達成をチャンネルでお知らせします。
+    .channel = お知らせするチャンネル
+announcements-disable = このサーバーでの成長のお知らせを停止します。
+leaderboard = このサーバーの認証済みメインキャラクターをランキングします。
+    .ranking = ランキングの基準
+    .job = ジョブのレベルでランキングする場合のジョブ
+language-user = ゲームデータを表示する言語を設定します。
+    .language = 使用する言語
+language-server = このサーバーでゲームデータを表示する言語を設定します。
+    .language = サーバーで使用する言語
+nicknames-enable = このサーバーのメンバーのニックネームを、紐付けたメインキャラクター名に変更します。
+    .format = ニックネームの書式
+nicknames-disable = このサーバーでのニックネームの変更を停止します。
+nicknames-sync = 紐付け済みのメンバー全員のニックネームを今すぐ変更します。
+roles-verified = メインキャラクターを認証したメンバーにロールを付与します。
+    .role = 付与するロール
+roles-world = メインキャラクターが特定のワールド、データセンター、または地域にいるメンバーにロールを付与します。
+    .world = ワールド、データセンター、または地域
+    .role = 付与するロール
+roles-grand-company = メインキャラクターが所属するグランドカンパニーに応じてロールを付与します。
+    .company = グランドカンパニー
+    .role = 付与するロール
+roles-combat-role = メインキャラクターの現在のジョブがタンク、ヒーラー、DPSのいずれかに応じてロールを付与します。
+    .kind = パーティでの役割
+    .role = 付与するロール
+roles-free-company = このサーバーのフリーカンパニーに所属するメンバーにロールを付与します。
+    .role = 付与するロール
+roles-remove = 設定したロールの付与を停止します。
+    .mapping = 削除する設定
+roles-list = このサーバーでKotonyaが付与するロールの一覧を表示します。
+roles-sync = このサーバーの紐付け済みメンバーのロールを今すぐ更新します。
+search-all = すべての種類からゲーム内のものを検索します。
+    .query = 検索する内容
+    .language = 結果を表示する言語
+search-achievement = アチーブメントを検索します。
+    .name = アチーブメント名
+    .language = 結果を表示する言語
+search-action = アクションを検索します。
+    .name = アクション名
+    .language = 結果を表示する言語
+search-emote = エモートを検索します。
+    .name = エモート名
+    .language = 結果を表示する言語
+search-enemy = エネミーを検索します。
+    .name = エネミー名
+    .language = 結果を表示する言語
+search-fate = F.A.T.E.を検索します。
+    .name = F.A.T.E.名
+    .language = 結果を表示する言語
+search-instance_content = インスタンスコンテンツを検索します。
+    .name = インスタンスコンテンツ名
+    .language = 結果を表示する言語
+search-item = アイテムを検索します。
+    .name = アイテム名
+    .language = 結果を表示する言語
+search-leve = リーヴを検索します。
+    .name = リーヴ名
+    .language = 結果を表示する言語
+search-minion = ミニオンを検索します。
+    .name = ミニオン名
+    .language = 結果を表示する言語
+search-mount = マウントを検索します。
+    .name = マウント名
+    .language = 結果を表示する言語
+search-npc = NPCを検索します。
+    .name = NPC名
+    .language = 結果を表示する言語
+search-place = 地名を検索します。
+    .name = 地名
+    .language = 結果を表示する言語
+search-quest = クエストを検索します。
+    .name = クエスト名
+    .language = 結果を表示する言語
+search-recipe = レシピを検索します。
+    .name = レシピ名
+    .language = 結果を表示する言語
+search-status = ステータスを検索します。
+    .name = ステータス名
+    .language = 結果を表示する言語
+search-title = 称号を検索します。
+    .name = 称号名
+    .language = 結果を表示する言語
+search-weather = 天候を検索します。
+    .name = 天候名
+    .language = 結果を表示する言語